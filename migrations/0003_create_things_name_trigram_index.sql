@@ -0,0 +1,7 @@
+-- migrations/{timestamp}_create_things_name_trigram_index.sql
+-- Enable trigram matching for Thing name autocomplete
+CREATE EXTENSION IF NOT EXISTS "pg_trgm";
+
+-- Create a trigram index for quicker prefix and similarity matching on name
+-- https://www.postgresql.org/docs/current/pgtrgm.html#PGTRGM-INDEX
+CREATE INDEX index_things_name_trigram ON things USING GIN (name gin_trgm_ops);
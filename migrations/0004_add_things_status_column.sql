@@ -0,0 +1,8 @@
+-- migrations/{timestamp}_add_things_status_column.sql
+-- Add a status to Things, so they can be archived without being deleted
+ALTER TABLE things
+    ADD COLUMN IF NOT EXISTS status VARCHAR NOT NULL DEFAULT 'active'
+    CHECK (status IN ('active', 'archived'));
+
+-- Create an index for quicker filtering by status
+CREATE INDEX index_things_status ON things (status);
@@ -0,0 +1,4 @@
+-- migrations/{timestamp}_add_things_slug_column.sql
+-- Add a URL friendly slug to Things, generated from the name by the services
+ALTER TABLE things
+    ADD COLUMN IF NOT EXISTS slug VARCHAR UNIQUE;
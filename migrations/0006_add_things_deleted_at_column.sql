@@ -0,0 +1,7 @@
+-- migrations/{timestamp}_add_things_deleted_at_column.sql
+-- Add a soft delete time stamp to Things, so deletes can be undone
+ALTER TABLE things
+    ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMP WITH TIME ZONE;
+
+-- Create a partial index for quicker filtering of Things that are not deleted
+CREATE INDEX index_things_not_deleted ON things (deleted_at) WHERE deleted_at IS NULL;
@@ -0,0 +1,12 @@
+-- migrations/{timestamp}_create_thing_notes_table.sql
+-- Create Thing Notes Table, notes are removed with their parent Thing
+CREATE TABLE IF NOT EXISTS thing_notes (
+    id UUID NOT NULL PRIMARY KEY,
+    thing_id UUID NOT NULL REFERENCES things (id) ON DELETE CASCADE,
+    body VARCHAR NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+-- Create an index for quicker listing of a Thing's notes in order
+CREATE INDEX index_thing_notes_thing_id_created_at ON thing_notes (thing_id, created_at);
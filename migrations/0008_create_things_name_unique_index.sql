@@ -0,0 +1,3 @@
+-- migrations/{timestamp}_create_things_name_unique_index.sql
+-- Thing names are unique, services map a violation to `ThingNameTaken`
+CREATE UNIQUE INDEX index_things_name_unique ON things (name);
@@ -0,0 +1,17 @@
+-- migrations/{timestamp}_create_users_and_verification_tokens_tables.sql
+-- Create Users Table, `verified_at` is set once the email address is verified
+CREATE TABLE IF NOT EXISTS users (
+    id UUID NOT NULL PRIMARY KEY,
+    email VARCHAR NOT NULL UNIQUE,
+    verified_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+-- Create Verification Tokens Table, tokens are removed with their user
+CREATE TABLE IF NOT EXISTS verification_tokens (
+    token VARCHAR NOT NULL PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    expires_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    consumed_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
@@ -0,0 +1,5 @@
+-- migrations/{timestamp}_add_things_version_column.sql
+-- Add an optimistic lock version to Things, incremented on every update, so
+-- concurrent updates can not silently overwrite each other
+ALTER TABLE things
+    ADD COLUMN IF NOT EXISTS version INTEGER NOT NULL DEFAULT 1;
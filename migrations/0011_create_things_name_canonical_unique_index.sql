@@ -0,0 +1,17 @@
+-- migrations/{timestamp}_create_things_name_canonical_unique_index.sql
+-- Thing names are unique ignoring case, matching `ThingName::canonical`
+DROP INDEX index_things_name_unique;
+
+-- Rename the Things whose name differs only by case from an older Thing's name,
+-- appending the random end of the Thing id, so the unique index can be created
+UPDATE things
+SET name = things.name || ' ' || right(things.id::text, 12),
+    updated_at = now(),
+    version = things.version + 1
+FROM (
+    SELECT id, row_number() OVER (PARTITION BY lower(name) ORDER BY created_at, id) AS position
+    FROM things
+) AS ranked
+WHERE things.id = ranked.id AND ranked.position > 1;
+
+CREATE UNIQUE INDEX index_things_name_unique ON things (lower(name));
@@ -0,0 +1,7 @@
+-- migrations/{timestamp}_create_things_full_text_search_index.sql
+-- Index the Thing name and description for full text search. An expression
+-- index is used rather than a generated `tsvector` column, so `SELECT *` still
+-- maps onto `ThingRow`. Queries must use the same expression to use the index.
+-- https://www.postgresql.org/docs/current/textsearch-tables.html#TEXTSEARCH-TABLES-INDEX
+CREATE INDEX index_things_full_text_search ON things
+    USING GIN (to_tsvector('english', name || ' ' || coalesce(description, '')));
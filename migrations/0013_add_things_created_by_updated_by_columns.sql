@@ -0,0 +1,6 @@
+-- migrations/{timestamp}_add_things_created_by_updated_by_columns.sql
+-- Record the user who created and last updated a Thing. Existing rows and
+-- unauthenticated requests use the nil system user id, see `SYSTEM_USER_ID`
+ALTER TABLE things
+    ADD COLUMN created_by UUID NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000',
+    ADD COLUMN updated_by UUID NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000';
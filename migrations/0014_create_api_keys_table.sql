@@ -0,0 +1,10 @@
+-- migrations/{timestamp}_create_api_keys_table.sql
+-- Create API Keys Table, only the SHA-256 hash of a key is stored and a key
+-- is revoked by setting `revoked_at`
+CREATE TABLE IF NOT EXISTS api_keys (
+    id UUID NOT NULL PRIMARY KEY,
+    name VARCHAR NOT NULL,
+    key_hash VARCHAR NOT NULL UNIQUE,
+    revoked_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
@@ -0,0 +1,14 @@
+-- migrations/{timestamp}_create_audit_log_table.sql
+-- Create Audit Log Table, a row is written with every mutation of an entity.
+-- `diff` is a JSON object of the changed fields and their `old` and `new` values
+CREATE TABLE IF NOT EXISTS audit_log (
+    id UUID NOT NULL PRIMARY KEY,
+    entity VARCHAR NOT NULL,
+    entity_id UUID NOT NULL,
+    action VARCHAR NOT NULL,
+    actor UUID NOT NULL,
+    diff JSONB NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+CREATE INDEX index_audit_log_entity ON audit_log (entity, entity_id);
@@ -0,0 +1,9 @@
+-- migrations/{timestamp}_create_idempotency_table.sql
+-- Create Idempotency Table, holding the response of each request made with an
+-- `Idempotency-Key` header so a retry gets the same response
+CREATE TABLE IF NOT EXISTS idempotency (
+    key VARCHAR NOT NULL PRIMARY KEY,
+    status_code SMALLINT NOT NULL,
+    body TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
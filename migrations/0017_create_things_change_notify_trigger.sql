@@ -0,0 +1,19 @@
+-- migrations/{timestamp}_create_things_change_notify_trigger.sql
+-- Notify the `thing_changes` channel of every insert, update and delete of a
+-- Thing, with a JSON payload of the `action` and the Thing `id`
+CREATE OR REPLACE FUNCTION notify_thing_change() RETURNS TRIGGER AS $$
+BEGIN
+    PERFORM pg_notify(
+        'thing_changes',
+        json_build_object(
+            'action', lower(TG_OP),
+            'id', CASE WHEN TG_OP = 'DELETE' THEN OLD.id ELSE NEW.id END
+        )::text
+    );
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER things_change_notify
+    AFTER INSERT OR UPDATE OR DELETE ON things
+    FOR EACH ROW EXECUTE FUNCTION notify_thing_change();
@@ -0,0 +1,18 @@
+-- migrations/{timestamp}_add_companies_timestamp_columns.sql
+-- Add created and updated timestamps to Companies, set by Postgres rather than
+-- the service, so they stay consistent with direct SQL edits
+ALTER TABLE companies
+    ADD COLUMN IF NOT EXISTS created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    ADD COLUMN IF NOT EXISTS updated_at TIMESTAMPTZ NOT NULL DEFAULT now();
+
+-- Set `updated_at` to the time of every update, overriding any given value
+CREATE OR REPLACE FUNCTION set_updated_at() RETURNS TRIGGER AS $$
+BEGIN
+    NEW.updated_at = now();
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER companies_set_updated_at
+    BEFORE UPDATE ON companies
+    FOR EACH ROW EXECUTE FUNCTION set_updated_at();
@@ -0,0 +1,15 @@
+-- migrations/{timestamp}_scope_and_claim_idempotency_keys.sql
+-- Scope idempotency keys to the client that sent them, so one client can not
+-- replay another's response, and allow a key to be claimed before its
+-- response is saved, so concurrent retries are not processed twice
+
+-- Saved responses are short lived, so existing ones are dropped rather than
+-- given a scope
+DELETE FROM idempotency;
+
+ALTER TABLE idempotency
+    ADD COLUMN scope VARCHAR NOT NULL,
+    ALTER COLUMN status_code DROP NOT NULL,
+    ALTER COLUMN body DROP NOT NULL,
+    DROP CONSTRAINT idempotency_pkey,
+    ADD PRIMARY KEY (scope, key);
@@ -1,23 +1,217 @@
 // -- ./src/api.rs
 
 //! Configure routes for api endpoints
-//! 
+//!
 //! # API Routes
-//! 
-//! API routes are abstracted into version `v1` for future breaking changes, if needed
+//!
+//! API routes are abstracted into versions, `v1` and `v2`, so breaking changes
+//! can be made in a new version without breaking existing clients
 
-use crate::routes;
+use crate::routes::{self, RouteDefinition};
 
 use actix_web::web;
 
+/// Scope that all version 1 routes are mounted under
+pub const V1_SCOPE: &str = "/api/v1";
+
+/// Modules (scopes) mounted under the version 1 scope and their routes, in
+/// order of configuration
+pub const V1_MODULES: &[(&str, &[RouteDefinition])] = &[
+    ("ping", routes::PING_ROUTES),
+    ("health_check", routes::HEALTH_CHECK_ROUTES),
+    ("info", routes::INFO_ROUTES),
+    ("things", routes::THINGS_ROUTES),
+    ("companies", routes::COMPANIES_ROUTES),
+    ("verify", routes::VERIFY_ROUTES),
+];
+
+/// Scope that all version 2 routes are mounted under
+pub const V2_SCOPE: &str = "/api/v2";
+
+/// Modules (scopes) mounted under the version 2 scope and their routes, in
+/// order of configuration
+///
+/// Version 2 starts with the version 1 `ping` route, so v2 specific handlers,
+/// i.e. a different `Paginated` envelope, can be added here without breaking
+/// version 1 clients.
+pub const V2_MODULES: &[(&str, &[RouteDefinition])] = &[("ping", routes::PING_ROUTES)];
+
+/// A route mounted on the running instance
+#[derive(Debug, Clone, PartialEq)]
+pub struct MountedRoute {
+    /// HTTP method the route responds to, i.e. `GET`
+    pub method: &'static str,
+    /// Full path of the route, including the api scope
+    pub path: String,
+}
+
 /// Version 1 root level routes are set up in this function
-/// 
+///
 /// # Parameters
-/// 
+///
 /// * `config`: is the Actix route config to build off
 pub fn v1(config: &mut web::ServiceConfig) {
-    config
-        .service(web::scope("/ping").configure(routes::ping))
-        .service(web::scope("/things").configure(routes::things))
-        .service(web::scope("/companies").configure(routes::companies));
+    configure_modules(config, V1_MODULES);
+}
+
+/// Enumerate the version 1 routes mounted by `v1`, returning the full path and
+/// method of each route.
+pub fn v1_routes() -> Vec<MountedRoute> {
+    mounted_routes(V1_SCOPE, V1_MODULES)
+}
+
+/// Version 2 root level routes are set up in this function
+///
+/// # Parameters
+///
+/// * `config`: is the Actix route config to build off
+pub fn v2(config: &mut web::ServiceConfig) {
+    configure_modules(config, V2_MODULES);
+}
+
+/// Enumerate the version 2 routes mounted by `v2`, returning the full path and
+/// method of each route.
+pub fn v2_routes() -> Vec<MountedRoute> {
+    mounted_routes(V2_SCOPE, V2_MODULES)
+}
+
+/// The names of `modules`, for logging
+pub fn module_names(modules: &[(&'static str, &[RouteDefinition])]) -> Vec<&'static str> {
+    modules.iter().map(|(module, _)| *module).collect()
+}
+
+/// Mount each of `modules` in a scope named after the module
+fn configure_modules(config: &mut web::ServiceConfig, modules: &'static [(&str, &[RouteDefinition])]) {
+    for (module, module_routes) in modules {
+        config.service(
+            web::scope(&format!("/{module}"))
+                .configure(|config| routes::configure(config, module_routes)),
+        );
+    }
+}
+
+/// The full path and method of each route in `modules`, mounted under `scope`
+fn mounted_routes(scope: &str, modules: &[(&str, &[RouteDefinition])]) -> Vec<MountedRoute> {
+    modules
+        .iter()
+        .flat_map(|(module, module_routes)| {
+            module_routes.iter().map(move |route| {
+                // Actix inserts a `/` between the scope and a non empty route path
+                let path = if route.path.is_empty() {
+                    format!("{scope}/{module}")
+                } else {
+                    format!("{scope}/{module}/{}", route.path)
+                };
+                MountedRoute { method: route.method, path }
+            })
+        })
+        .collect()
+}
+
+//-- Unit Tests
+#[cfg(test)]
+pub mod tests {
+    // Bring module functions into test scope
+    use super::*;
+
+    #[test]
+    fn v1_routes_includes_ping_health_check_info_and_things() {
+        //-- Execute Function (Act)
+        let mounted_routes = v1_routes();
+
+        //-- Checks (Assertions)
+        let expected = [
+            ("GET", "/api/v1/ping"),
+            ("GET", "/api/v1/health_check"),
+            ("GET", "/api/v1/info"),
+            ("GET", "/api/v1/things"),
+            ("POST", "/api/v1/things"),
+            ("DELETE", "/api/v1/things"),
+            ("POST", "/api/v1/things/import"),
+            ("GET", "/api/v1/things/export"),
+            ("GET", "/api/v1/things/events"),
+            ("GET", "/api/v1/things/ws"),
+            ("GET", "/api/v1/things/available"),
+            ("GET", "/api/v1/things/by-slug/{slug}"),
+            ("GET", "/api/v1/things/by-ids"),
+            ("GET", "/api/v1/things/count"),
+            ("GET", "/api/v1/things/{thing_id}"),
+            ("PUT", "/api/v1/things/{thing_id}"),
+            ("PATCH", "/api/v1/things/{thing_id}"),
+            ("DELETE", "/api/v1/things/{thing_id}"),
+            ("POST", "/api/v1/things/{thing_id}/restore"),
+            ("GET", "/api/v1/things/{thing_id}/notes"),
+            ("POST", "/api/v1/things/{thing_id}/notes"),
+            ("GET", "/api/v1/verify"),
+        ];
+        for (method, path) in expected {
+            assert!(
+                mounted_routes.contains(&MountedRoute { method, path: path.to_string() }),
+                "{method} {path} is not mounted"
+            );
+        }
+    }
+
+    #[actix_rt::test]
+    async fn v1_routes_are_served_by_the_app() {
+        //-- Setup and Fixtures (Arrange)
+        let app = actix_web::test::init_service(
+            actix_web::App::new().service(web::scope(V1_SCOPE).configure(v1)),
+        )
+        .await;
+
+        //-- Execute Function (Act)
+        let request = actix_web::test::TestRequest::get()
+            .uri(&format!("{V1_SCOPE}/ping"))
+            .to_request();
+        let response = actix_web::test::call_service(&app, request).await;
+
+        //-- Checks (Assertions)
+        assert!(response.status().is_success());
+    }
+
+    #[actix_rt::test]
+    async fn every_listed_route_is_mounted_on_the_app() {
+        //-- Setup and Fixtures (Arrange)
+        // Without app data the handlers fail their extractors, but a route
+        // that is not mounted is a `404` or `405` before any handler runs
+        let app = actix_web::test::init_service(
+            actix_web::App::new()
+                .service(web::scope(V1_SCOPE).configure(v1))
+                .service(web::scope(V2_SCOPE).configure(v2)),
+        )
+        .await;
+
+        for route in v1_routes().into_iter().chain(v2_routes()) {
+            //-- Execute Function (Act)
+            let uri = route
+                .path
+                .split('/')
+                .map(|segment| if segment.starts_with('{') { "0190b6f4-5b4e-7c1f-9a6e-0d8f3c2b1a00" } else { segment })
+                .collect::<Vec<_>>()
+                .join("/");
+            let request = actix_web::test::TestRequest::default()
+                .method(actix_web::http::Method::from_bytes(route.method.as_bytes()).unwrap())
+                .uri(&uri)
+                .to_request();
+            let response = actix_web::test::call_service(&app, request).await;
+
+            //-- Checks (Assertions)
+            assert!(
+                ![404, 405].contains(&response.status().as_u16()),
+                "{} {} is not mounted, got {}",
+                route.method,
+                route.path,
+                response.status()
+            );
+        }
+    }
+
+    #[test]
+    fn v2_routes_includes_ping() {
+        assert_eq!(
+            v2_routes(),
+            vec![MountedRoute { method: "GET", path: "/api/v2/ping".to_string() }]
+        );
+    }
 }
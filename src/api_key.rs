@@ -0,0 +1,56 @@
+// -- ./src/api_key.rs
+
+//! Authenticate service to service callers with an `X-Api-Key` header
+//!
+//! # API Key Middleware
+//!
+//! The `api_key` middleware looks up the `X-Api-Key` header of an incoming
+//! request through the `ApiKeyCache`. A valid key is stored in the request
+//! extensions as the `ApiKey` the request is authenticated as, and an unknown
+//! or revoked key is a `401 Unauthorized`. Requests without the header are
+//! passed through unauthenticated.
+
+use crate::error::Error;
+use crate::services::{self, api_keys::ApiKeyCache};
+
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::header::HeaderName;
+use actix_web::middleware::Next;
+use actix_web::web::Data;
+use actix_web::HttpMessage;
+use sqlx::PgPool;
+
+/// Header the API key is read from
+pub const API_KEY_HEADER: HeaderName = HeaderName::from_static("x-api-key");
+
+/// Middleware authenticating requests carrying an `X-Api-Key` header
+pub async fn api_key(
+	request: ServiceRequest,
+	next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+	let Some(key) = request.headers().get(API_KEY_HEADER) else {
+		return next.call(request).await;
+	};
+	let key = key
+		.to_str()
+		.map_err(|_| Error::from(services::Error::ApiKeyInvalid))?
+		.to_string();
+
+	let (Some(cache), Some(database)) = (
+		request.app_data::<Data<ApiKeyCache>>(),
+		request.app_data::<Data<PgPool>>(),
+	) else {
+		return Err(Error::Static("API key cache or database not configured").into());
+	};
+
+	let api_key = cache
+		.lookup(&key, database)
+		.await?
+		.ok_or(services::Error::ApiKeyInvalid)
+		.map_err(Error::from)?;
+	tracing::debug!(api_key_id = %api_key.id, "Request authenticated with an API key");
+	request.extensions_mut().insert(api_key);
+
+	next.call(request).await
+}
@@ -8,6 +8,33 @@
 //! overwrite with runtime environment configuration `./config/production.yaml`
 //! and environmental runtime variables.
 //!
+//! # Environment Variables
+//!
+//! Any setting can be overwritten with a `PL_` prefixed environment variable,
+//! using `__` to separate the sections, i.e.
+//!
+//! * `PL_APPLICATION__PORT` sets `application.port`
+//! * `PL_APPLICATION__PAGINATION__DEFAULT_LIMIT` sets the page size used when a
+//!   request has no `limit`
+//! * `PL_APPLICATION__PAGINATION__MAX_LIMIT` sets the largest page size a
+//!   request can have, larger limits are clamped
+//! * `PL_APPLICATION__WORKERS` pins the number of server worker threads
+//! * `PL_APPLICATION__RATE_LIMIT_PER_MINUTE` sets the requests a minute each
+//!   client can make, `0` disables the limit
+//! * `PL_APPLICATION__IDEMPOTENCY_TTL_SECONDS` sets how long the response to a
+//!   request with an `Idempotency-Key` header is replayed for
+//! * `PL_DATABASE__STARTUP_RETRIES` and `PL_DATABASE__RETRY_DELAY_MS` set how
+//!   often, and how long after, a failed startup connection is retried
+//! * `PL_DATABASE__RUN_MIGRATIONS=false` skips migrating the database at
+//!   startup, for deployments running `--migrate` as an init step
+//!
+//! # Secret Files
+//!
+//! The secrets in `SECRET_KEYS` can be read from a file, i.e. a mounted Docker
+//! or Kubernetes secret, by setting the key with a `_file` suffix to the file
+//! path. `PL_DATABASE__PASSWORD_FILE=/run/secrets/db_password` sets
+//! `database.password` to the file contents, without the trailing new line.
+//!
 //! # References
 //!
 //! * [config.rs Repository](https://github.com/mehcode/config-rs)
@@ -18,21 +45,47 @@
 use crate::prelude::*;
 
 use secrecy::{ExposeSecret, Secret};
-use serde_aux::field_attributes::deserialize_number_from_string;
+use serde_aux::field_attributes::{
+    deserialize_number_from_string, deserialize_option_number_from_string,
+};
 use sqlx::postgres::{PgConnectOptions, PgSslMode};
 use strum::{AsRefStr, Display};
 use std::path::PathBuf;
 
 /// Directory from binary base folder to look in for configuration files
 const CONFIGURATION_DIRECTORY_PREFIX: &str = "./configuration/";
+/// Secret settings that can be read from the file set in `<key>_file`
+const SECRET_KEYS: &[&str] = &["database.password", "email_client.authorisation_token"];
 /// If the configuration files do not set this default is used.
 const DEFAULT_RUNTIME_ENVIRONMENT: &str = "development";
 /// If the configuration files do not set this default is used.
 const DEFAULT_LOG_LEVEL: &str = "info";
+/// If the configuration files do not set this default is used, matching Actix.
+const DEFAULT_KEEP_ALIVE_SECONDS: u64 = 5;
+/// If the configuration files do not set this default is used, matching Actix.
+const DEFAULT_CLIENT_TIMEOUT_SECONDS: u64 = 5;
+/// If the configuration files do not set this default is used.
+const DEFAULT_RATE_LIMIT_PER_MINUTE: u32 = 600;
+/// If the configuration files do not set this default is used, one day.
+const DEFAULT_IDEMPOTENCY_TTL_SECONDS: u64 = 86_400;
+/// If the configuration files do not set this default is used.
+const DEFAULT_PAGINATION_LIMIT: i64 = 10;
+/// If the configuration files do not set this default is used.
+const DEFAULT_PAGINATION_MAX_LIMIT: i64 = 100;
+/// If the configuration files do not set this default is used.
+const DEFAULT_STATEMENT_TIMEOUT_MS: u64 = 30_000;
+/// If the configuration files do not set this default is used.
+const DEFAULT_MAX_CONNECTIONS: u32 = 10;
+/// If the configuration files do not set this default is used.
+const DEFAULT_ACQUIRE_TIMEOUT_SECONDS: u64 = 5;
 /// If the configuration files do not set this default is used.
-const DEFAULT_QUERY_OFFSET: i64 = 0;
+const DEFAULT_STARTUP_RETRIES: u32 = 5;
 /// If the configuration files do not set this default is used.
-const DEFAULT_QUERY_LIMIT: i64 = 10;
+const DEFAULT_RETRY_DELAY_MS: u64 = 500;
+/// If the configuration files do not set this default is used.
+const DEFAULT_RUN_MIGRATIONS: bool = true;
+/// If the configuration files do not set this default is used.
+const DEFAULT_MAX_BODY_BYTES: u64 = 64 * 1024;
 
 /// Configuration for the API
 #[derive(serde::Deserialize, Clone, Debug)]
@@ -52,6 +105,26 @@ pub enum LogLevels {
     Trace,
 }
 
+/// Define the log output formats the system will recognise
+#[derive(serde::Deserialize, Debug, Clone, AsRefStr, Display, Copy, PartialEq)]
+pub enum LogFormat {
+    /// Human readable records, for development
+    Pretty,
+    /// Bunyan formatted JSON records, for log aggregation
+    Json,
+}
+
+/// The log format used when the configuration files do not set one, pretty
+/// everywhere except production.
+impl From<Environment> for LogFormat {
+    fn from(environment: Environment) -> Self {
+        match environment {
+            Environment::Production => Self::Json,
+            Environment::Development | Environment::Testing => Self::Pretty,
+        }
+    }
+}
+
 /// Configuration for running the API application
 #[derive(serde::Deserialize, Clone, Debug)]
 pub struct ApplicationSettings {
@@ -62,19 +135,141 @@ pub struct ApplicationSettings {
 	pub port: u16,
     /// Application log level has a default set in builder
     pub log_level: LogLevels,
+    /// Application log output format, defaults to the runtime environment format
+    pub log_format: LogFormat,
     /// Application runtime environment is set to default in the builder
     pub runtime_environment: Environment,
-    /// Default application settings
-    pub default: DefaultApplicationSettings
+    /// Largest request body accepted in bytes, larger requests are rejected
+    /// with `413 Payload Too Large`
+	#[serde(deserialize_with = "deserialize_number_from_string")]
+	pub max_body_bytes: usize,
+    /// Page sizes for the index endpoints
+    pub pagination: PaginationSettings,
+    /// Number of server worker threads, defaults to one per CPU when not set
+	#[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+	pub workers: Option<usize>,
+    /// Seconds an idle keep-alive connection is held open
+	#[serde(deserialize_with = "deserialize_number_from_string")]
+	pub keep_alive_seconds: u64,
+    /// Seconds a client has to send the request head before the connection is
+    /// closed, bounding slow clients
+	#[serde(deserialize_with = "deserialize_number_from_string")]
+	pub client_timeout_seconds: u64,
+    /// Requests a minute each client, by API key or IP address, can make
+    /// before getting `429 Too Many Requests`, `0` disables the limit
+	#[serde(deserialize_with = "deserialize_number_from_string")]
+	pub rate_limit_per_minute: u32,
+    /// Seconds the response to a request with an `Idempotency-Key` header is
+    /// replayed for, after which the key can be used again
+	#[serde(deserialize_with = "deserialize_number_from_string")]
+	pub idempotency_ttl_seconds: u64,
 }
 
-/// Default application settings
-#[derive(serde::Deserialize, Clone, Debug)]
-pub struct DefaultApplicationSettings {
-    // Default sql query offset
-    pub query_offset: i64,
-    // Default sql query limit
-    pub query_limit: i64
+impl ApplicationSettings {
+    /// Check the settings can be used to start the server, returning a
+    /// descriptive `Error::Config` rather than failing later when binding.
+    pub fn validate(&self) -> Result<()> {
+        validate_address(&self.address)?;
+        validate_workers(self.workers)?;
+        self.pagination.validate()
+    }
+}
+
+/// Override each of the `SECRET_KEYS` that has a `<key>_file` setting with the
+/// contents of the file, returning `Error::Config` if a file can not be read
+fn with_secret_files(configuration: config::Config) -> Result<config::Config> {
+    let mut builder = config::Config::builder().add_source(configuration.clone());
+    for key in SECRET_KEYS {
+        let file_key = format!("{key}_file");
+        let Ok(path) = configuration.get_string(&file_key) else {
+            continue;
+        };
+
+        let secret = std::fs::read_to_string(&path).map_err(|error| {
+            config::ConfigError::Message(format!("{file_key} {path:?} could not be read: {error}"))
+        })?;
+        builder = builder.set_override(*key, secret.trim_end_matches(['\r', '\n']))?;
+    }
+
+    Ok(builder.build()?)
+}
+
+/// Check `address` is an IP address or a host name the server can bind to
+fn validate_address(address: &str) -> Result<()> {
+    let invalid = |reason: &str| -> Result<()> {
+        Err(config::ConfigError::Message(format!(
+            "application.address {address:?} is not valid, {reason}"
+        ))
+        .into())
+    };
+
+    if address.trim().is_empty() {
+        return invalid("it must be set to an IP address or host name");
+    }
+    if address.parse::<std::net::IpAddr>().is_ok() {
+        return Ok(());
+    }
+
+    // A host name is dot separated labels of ascii letters, digits and hyphens
+    let is_label = |label: &str| {
+        !label.is_empty()
+            && label.len() <= 63
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    };
+    if address.len() > 253 || !address.split('.').all(is_label) {
+        return invalid("it is neither an IP address nor a host name");
+    }
+
+    Ok(())
+}
+
+/// Check a set `workers` count is at least 1, Actix panics on `0` workers
+fn validate_workers(workers: Option<usize>) -> Result<()> {
+    if workers == Some(0) {
+        return Err(config::ConfigError::Message(
+            "application.workers must be at least 1, or unset for one per CPU".to_string(),
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Page size settings for the index endpoints
+#[derive(serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct PaginationSettings {
+    /// Page size used when a request has no `limit`
+	#[serde(deserialize_with = "deserialize_number_from_string")]
+    pub default_limit: i64,
+    /// Largest page size a request can have, larger limits are clamped
+	#[serde(deserialize_with = "deserialize_number_from_string")]
+    pub max_limit: i64,
+}
+
+impl Default for PaginationSettings {
+    fn default() -> Self {
+        Self {
+            default_limit: DEFAULT_PAGINATION_LIMIT,
+            max_limit: DEFAULT_PAGINATION_MAX_LIMIT,
+        }
+    }
+}
+
+impl PaginationSettings {
+    /// Check the default limit is a page size within the maximum limit
+    fn validate(&self) -> Result<()> {
+        if self.default_limit < 1 || self.default_limit > self.max_limit {
+            return Err(config::ConfigError::Message(format!(
+                "application.pagination.default_limit {} must be between 1 and max_limit {}",
+                self.default_limit, self.max_limit
+            ))
+            .into());
+        }
+
+        Ok(())
+    }
 }
 
 /// Configuration for connecting to the database server
@@ -91,8 +286,28 @@ pub struct DatabaseSettings {
     pub password: Secret<String>,
     /// Database name to use
     pub database_name: String,
-    /// Should ssl be used to connect to the database
+    /// Should ssl be used to connect to the database. When true the server
+    /// fails at startup if the database connection is not encrypted.
 	pub require_ssl: bool,
+    /// Postgres `statement_timeout` in milliseconds set on each connection, 
+    /// cancelling runaway queries. `0` disables the timeout.
+	#[serde(deserialize_with = "deserialize_number_from_string")]
+	pub statement_timeout_ms: u64,
+    /// Maximum number of connections the pool will open to the database
+	#[serde(deserialize_with = "deserialize_number_from_string")]
+	pub max_connections: u32,
+    /// How long to wait for a pool connection before erroring, in seconds
+	#[serde(deserialize_with = "deserialize_number_from_string")]
+	pub acquire_timeout_seconds: u64,
+    /// Times a failed connection and migration at startup is retried, so the
+    /// server can start before the database is ready
+	#[serde(deserialize_with = "deserialize_number_from_string")]
+	pub startup_retries: u32,
+    /// Milliseconds before the first startup retry, doubling for each retry
+	#[serde(deserialize_with = "deserialize_number_from_string")]
+	pub retry_delay_ms: u64,
+    /// Should the migrations be run when the connection pool is created
+	pub run_migrations: bool,
 }
 
 impl DatabaseSettings {
@@ -111,10 +326,20 @@ impl DatabaseSettings {
             .ssl_mode(ssl_mode)
     }
 
-    pub fn connection_url(&self) -> String {
-        format!(
+    /// The database URL, including the password, so it is wrapped in a
+    /// `Secret` rather than logged by mistake
+    pub fn connection_url(&self) -> Secret<String> {
+        Secret::new(format!(
             "postgres://{}:{}@{}:{}/{}",
             self.username, self.password.expose_secret(), self.host, self.port, self.database_name
+        ))
+    }
+
+    /// The database URL with the password replaced by `REDACTED`, for logging
+    pub fn redacted_connection_url(&self) -> String {
+        format!(
+            "postgres://{}:REDACTED@{}:{}/{}",
+            self.username, self.host, self.port, self.database_name
         )
     }
 }
@@ -190,9 +415,10 @@ impl Configuration {
             .join(CONFIGURATION_DIRECTORY_PREFIX);
         // dbg!(base_dir_path);
 
+        let runtime_environment = get_runtime_environment()?;
         let environment_filename = format!(
             "{}.yaml", 
-            get_runtime_environment()?.as_str()
+            runtime_environment.as_str()
         );
         // dbg!(environment_filename);
 
@@ -216,12 +442,60 @@ impl Configuration {
                 DEFAULT_LOG_LEVEL
             )?
             .set_default(
-                "application.default.query_offset",
-                DEFAULT_QUERY_OFFSET
+                "application.log_format",
+                LogFormat::from(runtime_environment).as_ref()
+            )?
+            .set_default(
+                "application.max_body_bytes",
+                DEFAULT_MAX_BODY_BYTES
+            )?
+            .set_default(
+                "application.keep_alive_seconds",
+                DEFAULT_KEEP_ALIVE_SECONDS
+            )?
+            .set_default(
+                "application.client_timeout_seconds",
+                DEFAULT_CLIENT_TIMEOUT_SECONDS
+            )?
+            .set_default(
+                "application.rate_limit_per_minute",
+                DEFAULT_RATE_LIMIT_PER_MINUTE
+            )?
+            .set_default(
+                "application.idempotency_ttl_seconds",
+                DEFAULT_IDEMPOTENCY_TTL_SECONDS
+            )?
+            .set_default(
+                "application.pagination.default_limit",
+                DEFAULT_PAGINATION_LIMIT
+            )?
+            .set_default(
+                "application.pagination.max_limit",
+                DEFAULT_PAGINATION_MAX_LIMIT
+            )?
+            .set_default(
+                "database.statement_timeout_ms",
+                DEFAULT_STATEMENT_TIMEOUT_MS
+            )?
+            .set_default(
+                "database.max_connections",
+                DEFAULT_MAX_CONNECTIONS
             )?
             .set_default(
-                "application.default.query_limit",
-                DEFAULT_QUERY_LIMIT
+                "database.acquire_timeout_seconds",
+                DEFAULT_ACQUIRE_TIMEOUT_SECONDS
+            )?
+            .set_default(
+                "database.startup_retries",
+                DEFAULT_STARTUP_RETRIES
+            )?
+            .set_default(
+                "database.retry_delay_ms",
+                DEFAULT_RETRY_DELAY_MS
+            )?
+            .set_default(
+                "database.run_migrations",
+                DEFAULT_RUN_MIGRATIONS
             )?
             .add_source(config::File::from(
                 base_dir_path.join("base.yaml"),
@@ -240,7 +514,9 @@ impl Configuration {
             )
             .build()?;
 
-        let configuration = configuration_builder.try_deserialize::<Configuration>()?;
+        let configuration = with_secret_files(configuration_builder)?
+            .try_deserialize::<Configuration>()?;
+        configuration.application.validate()?;
 
         tracing::debug!(
             "\n----------- CONFIGURATION ----------- \n{:?} \n-------------------------------------",
@@ -250,27 +526,344 @@ impl Configuration {
         // Convert the configuration values into Settings type
         Ok(configuration)
     }
+
+    /// Check the settings make sense together, returning a single
+    /// `Error::Config` listing every problem found, so they can all be fixed
+    /// before the next start.
+    pub fn validate(&self) -> Result<()> {
+        let mut problems: Vec<String> = Vec::new();
+        let mut check = |is_valid: bool, problem: &str| {
+            if !is_valid {
+                problems.push(problem.to_string());
+            }
+        };
+
+        let application = &self.application;
+        check(
+            application.port != 0 || application.runtime_environment != Environment::Production,
+            "application.port must be set in production",
+        );
+        let database = &self.database;
+        check(!database.host.trim().is_empty(), "database.host must not be blank");
+        check(!database.database_name.trim().is_empty(), "database.database_name must not be blank");
+        check(database.max_connections > 0, "database.max_connections must be at least 1");
+        check(database.acquire_timeout_seconds > 0, "database.acquire_timeout_seconds must be at least 1");
+        let email_client = &self.email_client;
+        check(!email_client.base_url.trim().is_empty(), "email_client.base_url must not be blank");
+        check(!email_client.sender_email.trim().is_empty(), "email_client.sender_email must not be blank");
+        check(email_client.timeout_milliseconds > 0, "email_client.timeout_milliseconds must be at least 1");
+
+        if let Err(error) = validate_address(&application.address) {
+            problems.push(error.to_string());
+        }
+        if let Err(error) = validate_workers(application.workers) {
+            problems.push(error.to_string());
+        }
+        if let Err(error) = application.pagination.validate() {
+            problems.push(error.to_string());
+        }
+
+        if problems.is_empty() {
+            return Ok(());
+        }
+
+        Err(config::ConfigError::Message(format!(
+            "Invalid configuration: {}",
+            problems.join("; ")
+        ))
+        .into())
+    }
 }
 
 //-- Unit Tests
-// #[cfg(test)]
-// pub mod tests {
-
-//     // Override with more flexible error
-//     pub type Result<T> = core::result::Result<T, Error>;
-// 	pub type Error = Box<dyn std::error::Error>;
-
-//     // Bring module functions into test scope
-//     use super::*;
-
-//     // Test creating a new Thing without a description
-//     #[test]
-//     fn default_config() -> Result<()> {
-//         let configuration: Configuration = Configuration::parse()?;
-//         // dbg!(_configuration);
-//         assert!(configuration.application.address.is_empty());
-//         // assert_eq!(configuration.get("application.address").ok(), "127.0.0.1");
-//         Ok(())
-//     }
-
-// }
+#[cfg(test)]
+pub mod tests {
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>;
+
+    // Bring module functions into test scope
+    use super::*;
+
+    // Test the configuration files parse with a valid address
+    #[test]
+    fn default_config() -> Result<()> {
+        let configuration: Configuration = Configuration::parse()?;
+        assert!(!configuration.application.address.is_empty());
+        assert!(configuration.application.validate().is_ok());
+        assert_eq!(configuration.application.keep_alive_seconds, DEFAULT_KEEP_ALIVE_SECONDS);
+        assert_eq!(configuration.application.client_timeout_seconds, DEFAULT_CLIENT_TIMEOUT_SECONDS);
+        assert_eq!(configuration.application.rate_limit_per_minute, DEFAULT_RATE_LIMIT_PER_MINUTE);
+        assert_eq!(configuration.application.idempotency_ttl_seconds, DEFAULT_IDEMPOTENCY_TTL_SECONDS);
+        Ok(())
+    }
+
+    // Test a secret is read from the file set in its `_file` key
+    #[test]
+    fn secrets_are_read_from_files() -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let secret = format!("secret-{}", uuid::Uuid::now_v7());
+        let path = std::env::temp_dir().join(format!("{secret}.txt"));
+        std::fs::write(&path, format!("{secret}\n"))?;
+        let yaml = format!(
+            r#"
+            database:
+                host: "localhost"
+                port: 5432
+                username: "postgres"
+                password: "from-yaml"
+                password_file: {path:?}
+                database_name: "personal_ledger_test"
+                require_ssl: false
+                statement_timeout_ms: 30000
+                max_connections: 10
+                acquire_timeout_seconds: 5
+                startup_retries: 5
+                retry_delay_ms: 500
+                run_migrations: true
+            "#
+        );
+        let configuration = config::Config::builder()
+            .add_source(config::File::from_str(&yaml, config::FileFormat::Yaml))
+            .build()?;
+
+        //-- Execute Function (Act)
+        let database: DatabaseSettings = with_secret_files(configuration)?.get("database")?;
+        std::fs::remove_file(&path)?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database.password.expose_secret(), &secret);
+        // The secret is redacted when the settings are logged
+        assert!(!format!("{database:?}").contains(&secret));
+
+        Ok(())
+    }
+
+    // Test an unreadable secret file is a configuration error
+    #[test]
+    fn unreadable_secret_file_is_rejected() -> Result<()> {
+        let configuration = config::Config::builder()
+            .set_override("database.password_file", "/does/not/exist")?
+            .build()?;
+
+        let error = with_secret_files(configuration).unwrap_err();
+
+        assert!(matches!(error, crate::error::Error::Config(_)));
+        assert!(error.to_string().contains("database.password_file"));
+
+        Ok(())
+    }
+
+    // Test the database password is not in the debug output or logged url
+    #[test]
+    fn secrets_are_redacted() -> Result<()> {
+        let mut configuration = Configuration::parse()?;
+        let password = format!("password-{}", uuid::Uuid::now_v7());
+        configuration.database.password = Secret::new(password.clone());
+
+        assert!(!format!("{:?}", configuration.database).contains(&password));
+        assert!(!format!("{configuration:?}").contains(&password));
+        assert!(!configuration.database.redacted_connection_url().contains(&password));
+        assert!(configuration.database.redacted_connection_url().contains("REDACTED"));
+        assert!(configuration.database.connection_url().expose_secret().contains(&password));
+
+        Ok(())
+    }
+
+    // Test every configuration problem is reported in a single error
+    #[test]
+    fn invalid_configuration_reports_all_problems() -> Result<()> {
+        let mut configuration = Configuration::parse()?;
+        assert!(configuration.validate().is_ok());
+        configuration.email_client.timeout_milliseconds = 0;
+        configuration.database.database_name = " ".to_string();
+
+        let error = configuration.validate().unwrap_err();
+
+        assert!(matches!(error, crate::error::Error::Config(_)));
+        let message = error.to_string();
+        assert!(message.contains("email_client.timeout_milliseconds must be at least 1"));
+        assert!(message.contains("database.database_name must not be blank"));
+
+        Ok(())
+    }
+
+    // Test a zero port is only rejected in production
+    #[test]
+    fn zero_port_is_rejected_in_production() -> Result<()> {
+        let mut configuration = Configuration::parse()?;
+        configuration.application.port = 0;
+        assert!(configuration.validate().is_ok());
+
+        configuration.application.runtime_environment = Environment::Production;
+        assert!(configuration.validate().unwrap_err().to_string().contains("application.port"));
+
+        Ok(())
+    }
+
+    // Test an empty or malformed address is rejected with a clear message
+    #[test]
+    fn invalid_address_is_rejected() {
+        let error = validate_address("").unwrap_err();
+        assert!(matches!(error, crate::error::Error::Config(_)));
+        assert!(error.to_string().contains("application.address \"\" is not valid"));
+
+        assert!(validate_address("   ").is_err());
+        assert!(validate_address("127.0.0.1:8080").is_err());
+        assert!(validate_address("bad host").is_err());
+        assert!(validate_address("-leading.example.com").is_err());
+    }
+
+    // Application settings as strings, the way environment variables set them
+    fn application_settings_json() -> serde_json::Value {
+        serde_json::json!({
+            "address": "127.0.0.1",
+            "port": "8091",
+            "log_level": "Info",
+            "log_format": "Pretty",
+            "runtime_environment": "Development",
+            "max_body_bytes": "65536",
+            "keep_alive_seconds": "5",
+            "client_timeout_seconds": "5",
+            "rate_limit_per_minute": "600",
+            "idempotency_ttl_seconds": "86400",
+            "pagination": { "default_limit": "10", "max_limit": "100" },
+        })
+    }
+
+    // Test the worker count is optional and deserializes from an environment string
+    #[test]
+    fn workers_setting_deserializes() -> Result<()> {
+        let mut settings = application_settings_json();
+        let without_workers: ApplicationSettings = serde_json::from_value(settings.clone())?;
+        settings["workers"] = "2".into();
+        let with_workers: ApplicationSettings = serde_json::from_value(settings)?;
+
+        assert_eq!(without_workers.workers, None);
+        assert_eq!(with_workers.workers, Some(2));
+
+        Ok(())
+    }
+
+    // Test a zero worker count is rejected, rather than panicking at startup
+    #[test]
+    fn zero_workers_is_rejected() -> Result<()> {
+        let mut configuration = Configuration::parse()?;
+        configuration.application.workers = Some(1);
+        assert!(configuration.application.validate().is_ok());
+
+        configuration.application.workers = Some(0);
+
+        let error = configuration.application.validate().unwrap_err();
+        assert!(matches!(error, crate::error::Error::Config(_)));
+        assert!(error.to_string().contains("application.workers must be at least 1"));
+        assert!(configuration.validate().unwrap_err().to_string().contains("application.workers"));
+
+        Ok(())
+    }
+
+    // Test the connection timeouts deserialize from environment strings
+    #[test]
+    fn connection_timeout_settings_deserialize() -> Result<()> {
+        let mut settings = application_settings_json();
+        settings["keep_alive_seconds"] = "75".into();
+        settings["client_timeout_seconds"] = "10".into();
+
+        let settings: ApplicationSettings = serde_json::from_value(settings)?;
+
+        assert_eq!(settings.keep_alive_seconds, 75);
+        assert_eq!(settings.client_timeout_seconds, 10);
+
+        Ok(())
+    }
+
+    // Test a default page size outside the maximum is rejected
+    #[test]
+    fn invalid_pagination_is_rejected() {
+        assert!(PaginationSettings::default().validate().is_ok());
+
+        let zero_default = PaginationSettings { default_limit: 0, max_limit: 100 };
+        assert!(matches!(zero_default.validate(), Err(crate::error::Error::Config(_))));
+
+        let over_max = PaginationSettings { default_limit: 101, max_limit: 100 };
+        assert!(over_max.validate().unwrap_err().to_string().contains("default_limit 101"));
+    }
+
+    // Test IP addresses and host names are accepted
+    #[test]
+    fn valid_address_is_accepted() {
+        assert!(validate_address("127.0.0.1").is_ok());
+        assert!(validate_address("0.0.0.0").is_ok());
+        assert!(validate_address("::1").is_ok());
+        assert!(validate_address("localhost").is_ok());
+        assert!(validate_address("api.example-host.com").is_ok());
+    }
+
+    // Test the connection options target the configured database
+    #[test]
+    fn connection_targets_configured_database() -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let database = DatabaseSettings {
+            host: "localhost".to_string(),
+            port: 5432,
+            username: "postgres".to_string(),
+            password: Secret::new("password".to_string()),
+            database_name: "personal_ledger_test".to_string(),
+            require_ssl: false,
+            statement_timeout_ms: 30_000,
+            max_connections: 10,
+            acquire_timeout_seconds: 5,
+            startup_retries: 5,
+            retry_delay_ms: 500,
+            run_migrations: true,
+        };
+
+        //-- Execute Function (Act)
+        let connection = database.connection();
+
+        //-- Checks (Assertions)
+        assert_eq!(connection.get_database(), Some("personal_ledger_test"));
+        assert_eq!(connection.get_host(), "localhost");
+        assert_eq!(connection.get_port(), 5432);
+        assert_eq!(connection.get_username(), "postgres");
+
+        Ok(())
+    }
+
+    // Test the pool settings deserialize from configuration
+    #[test]
+    fn database_pool_settings_deserialize() -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let yaml = r#"
+            host: "localhost"
+            port: 5432
+            username: "postgres"
+            password: "password"
+            database_name: "personal_ledger_test"
+            require_ssl: false
+            statement_timeout_ms: 30000
+            max_connections: 25
+            acquire_timeout_seconds: 3
+            startup_retries: 2
+            retry_delay_ms: 100
+            run_migrations: false
+        "#;
+
+        //-- Execute Function (Act)
+        let database: DatabaseSettings = config::Config::builder()
+            .add_source(config::File::from_str(yaml, config::FileFormat::Yaml))
+            .build()?
+            .try_deserialize()?;
+
+        //-- Checks (Assertions)
+        assert_eq!(database.max_connections, 25);
+        assert_eq!(database.acquire_timeout_seconds, 3);
+        assert_eq!(database.startup_retries, 2);
+        assert_eq!(database.retry_delay_ms, 100);
+        assert!(!database.run_migrations);
+
+        Ok(())
+    }
+
+}
@@ -0,0 +1,13 @@
+use chrono::{DateTime, Utc};
+
+/// An optional window of UTC timestamps, for filtering records by date
+///
+/// Both bounds are inclusive and independent, a missing bound leaves that side
+/// of the window open.
+#[derive(Clone, Copy, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct DateTimeRange {
+	/// Only include records at or after this timestamp
+	pub after: Option<DateTime<Utc>>,
+	/// Only include records at or before this timestamp
+	pub before: Option<DateTime<Utc>>,
+}
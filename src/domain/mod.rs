@@ -1,3 +1,16 @@
+mod date_time_range;
+mod notes;
+mod paginated;
+mod slug_name;
 mod things;
+pub mod timestamp;
+mod time_zone;
+mod uuid_v7;
 
-pub use things::*;
\ No newline at end of file
+pub use date_time_range::DateTimeRange;
+pub use notes::*;
+pub use paginated::Paginated;
+pub use slug_name::SlugName;
+pub use things::*;
+pub use time_zone::ClientTimeZone;
+pub use uuid_v7::uuid_from_datetime;
@@ -0,0 +1,49 @@
+mod note_body;
+mod note_row;
+
+pub use note_body::NoteBody;
+pub use note_row::NoteRow;
+
+use chrono::prelude::*;
+use uuid::Uuid;
+
+/// A Note struct model, a timestamped free text note on a parent `Thing`.
+///
+/// The model should be consistent with the `thing_notes` database table model
+/// defined in the folder `./migrations`.
+#[derive(
+	Clone,
+	Debug,
+	PartialEq,
+	serde::Deserialize,
+	serde::Serialize,
+)]
+pub struct Note {
+	/// The Note `id` as a Unique identifier (v7) and cannot be null in the database.
+	pub id: Uuid,
+	/// The `id` of the parent Thing the note belongs to.
+	pub thing_id: Uuid,
+	/// The Note `body` is a String and cannot be null in the database.
+	pub body: NoteBody,
+	/// The Note `created_at` is a time zone time stamp and cannot be null in
+	/// the database. Notes are ordered by it.
+	pub created_at: DateTime<Utc>,
+	/// The Note `updated_at` is a time zone time stamp and cannot be null in
+	/// the database.
+	pub updated_at: DateTime<Utc>,
+}
+
+impl Note {
+	/// Create a new Note on the Thing with `thing_id`, with a new id and the
+	/// current time stamps.
+	pub fn new(thing_id: Uuid, body: NoteBody) -> Self {
+		let now = Utc::now();
+		Note {
+			id: Uuid::now_v7(),
+			thing_id,
+			body,
+			created_at: now,
+			updated_at: now,
+		}
+	}
+}
@@ -0,0 +1,76 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::prelude::*;
+extern crate derive_more;
+
+#[derive(
+	Clone,
+	serde::Deserialize,
+	Debug,
+	serde::Serialize,
+	PartialEq,
+	derive_more::From,
+	derive_more::Into,
+	derive_more::AsRef,
+	sqlx::Type,
+)]
+pub struct NoteBody(String);
+
+impl NoteBody {
+	/// Returns an instance of `NoteBody` if the input is not empty and no longer
+	/// than 2048 graphemes, or a `NoteBodyValidationError` otherwise.
+	pub fn parse(body: impl Into<String>) -> Result<NoteBody> {
+		let body: String = body.into();
+
+		let is_empty_or_whitespace = body.trim().is_empty();
+
+		// Notes are free text, so only the length is limited
+		let is_too_long = body.graphemes(true).count() > 2048;
+
+		if is_empty_or_whitespace || is_too_long {
+			Err(Error::NoteBodyValidationError { body })
+		} else {
+			Ok(Self(body))
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// Override with more flexible error
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>;
+
+	use crate::domain::NoteBody;
+	use claim::{assert_err, assert_ok};
+	use fake::faker::lorem::en::Paragraph;
+	use fake::Fake;
+
+	#[test]
+	fn a_2048_grapheme_long_body_is_valid() {
+		let body = "a̐".repeat(2048);
+		assert_ok!(NoteBody::parse(body));
+	}
+
+	#[test]
+	fn a_body_longer_than_2048_graphemes_is_rejected() {
+		let body = "a".repeat(2049);
+		assert!(matches!(
+			NoteBody::parse(body),
+			Err(crate::error::Error::NoteBodyValidationError { .. })
+		));
+	}
+
+	#[test]
+	fn whitespace_only_bodies_are_rejected() {
+		assert_err!(NoteBody::parse(" \n\t"));
+	}
+
+	#[test]
+	fn a_valid_body_is_parsed_successfully() -> Result<()> {
+		let body: String = Paragraph(1..3).fake();
+		assert_eq!(NoteBody::parse(body.clone())?.as_ref(), &body);
+
+		Ok(())
+	}
+}
@@ -0,0 +1,32 @@
+use chrono::prelude::*;
+use uuid::Uuid;
+
+use crate::domain::{Note, NoteBody};
+use crate::prelude::*;
+
+/// A `thing_notes` database table row.
+///
+/// Services query rows into this struct, then convert it into a `Note`, which
+/// validates the body.
+#[derive(Clone, Debug, PartialEq, sqlx::FromRow)]
+pub struct NoteRow {
+	pub id: Uuid,
+	pub thing_id: Uuid,
+	pub body: String,
+	pub created_at: DateTime<Utc>,
+	pub updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<NoteRow> for Note {
+	type Error = Error;
+
+	fn try_from(row: NoteRow) -> Result<Self> {
+		Ok(Note {
+			id: row.id,
+			thing_id: row.thing_id,
+			body: NoteBody::parse(row.body)?,
+			created_at: row.created_at,
+			updated_at: row.updated_at,
+		})
+	}
+}
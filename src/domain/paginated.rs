@@ -0,0 +1,12 @@
+/// A page of records along with the metadata a client needs to build a pager.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Paginated<T> {
+	/// The records in this page
+	pub data: Vec<T>,
+	/// The total number of records, not the page length
+	pub total: i64,
+	/// The maximum number of records in a page
+	pub limit: i64,
+	/// The number of records skipped before this page
+	pub offset: i64,
+}
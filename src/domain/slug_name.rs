@@ -0,0 +1,138 @@
+use crate::domain::ThingName;
+use crate::prelude::*;
+extern crate derive_more;
+
+/// A URL friendly name, derived from a display name
+///
+/// Slugs are lowercase, with whitespace replaced by hyphens and any other
+/// characters that are not alphanumeric or hyphens stripped. Unicode letters
+/// and numbers are kept, so `Crème Brûlée` becomes `crème-brûlée`.
+#[derive(
+	Clone,
+	serde::Deserialize,
+	Debug,
+	serde::Serialize,
+	PartialEq,
+	derive_more::Into,
+	derive_more::AsRef,
+	sqlx::Type,
+)]
+pub struct SlugName(String);
+
+impl SlugName {
+	/// Returns a `SlugName` generated from `value`, or a
+	/// `SlugNameValidationError` if nothing remains once the value is slugified.
+	pub fn parse(value: impl Into<String>) -> Result<SlugName> {
+		let value: String = value.into();
+
+		let mut slug = String::with_capacity(value.len());
+		for character in value.chars().flat_map(char::to_lowercase) {
+			if character.is_alphanumeric() {
+				slug.push(character);
+			} else if (character.is_whitespace() || character == '-') && !slug.ends_with('-') {
+				// Collapse runs of whitespace and hyphens into a single hyphen
+				slug.push('-');
+			}
+		}
+		let slug = slug.trim_matches('-');
+
+		if slug.is_empty() {
+			Err(Error::SlugNameValidationError { slug: value })
+		} else {
+			Ok(Self(slug.to_string()))
+		}
+	}
+
+	/// Returns the first of `slug`, `slug-2`, `slug-3`, ... that is not in
+	/// `taken`, so the slug can be stored against a unique constraint.
+	pub fn deduplicate<'a>(&self, taken: impl IntoIterator<Item = &'a str>) -> SlugName {
+		let taken: Vec<&str> = taken.into_iter().collect();
+		if !taken.contains(&self.0.as_str()) {
+			return self.clone();
+		}
+
+		(2..)
+			.map(|counter| format!("{}-{counter}", self.0))
+			.find(|slug| !taken.contains(&slug.as_str()))
+			.map(Self)
+			.unwrap()
+	}
+}
+
+impl TryFrom<&ThingName> for SlugName {
+	type Error = Error;
+
+	fn try_from(name: &ThingName) -> Result<Self> {
+		SlugName::parse(name.as_ref())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// Override with more flexible error
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>;
+
+	use crate::domain::{SlugName, ThingName};
+	use claim::assert_err;
+
+	#[test]
+	fn a_name_is_lowercased_and_hyphenated() -> Result<()> {
+		let slug = SlugName::parse("My  First Thing")?;
+		assert_eq!(slug.as_ref(), "my-first-thing");
+
+		Ok(())
+	}
+
+	#[test]
+	fn non_alphanumeric_characters_are_stripped() -> Result<()> {
+		let slug = SlugName::parse(" Fish & Chips, 2 for $10! ")?;
+		assert_eq!(slug.as_ref(), "fish-chips-2-for-10");
+
+		Ok(())
+	}
+
+	#[test]
+	fn unicode_names_keep_their_letters() -> Result<()> {
+		assert_eq!(SlugName::parse("Crème Brûlée")?.as_ref(), "crème-brûlée");
+		assert_eq!(SlugName::parse("ÜNÏCÖDÉ Ñame")?.as_ref(), "ünïcödé-ñame");
+		assert_eq!(SlugName::parse("東京 タワー")?.as_ref(), "東京-タワー");
+
+		Ok(())
+	}
+
+	#[test]
+	fn a_slug_of_only_stripped_characters_is_rejected() {
+		assert_err!(SlugName::parse("!?&"));
+		assert!(matches!(
+			SlugName::parse("  - "),
+			Err(crate::error::Error::SlugNameValidationError { .. })
+		));
+	}
+
+	#[test]
+	fn a_thing_name_converts_into_a_slug() -> Result<()> {
+		let name = ThingName::parse("Garden Shed")?;
+		let slug = SlugName::try_from(&name)?;
+		assert_eq!(slug.as_ref(), "garden-shed");
+
+		Ok(())
+	}
+
+	#[test]
+	fn a_free_slug_is_not_suffixed() -> Result<()> {
+		let slug = SlugName::parse("shed")?;
+		assert_eq!(slug.deduplicate(["garden", "shed-2"]), slug);
+
+		Ok(())
+	}
+
+	#[test]
+	fn a_taken_slug_is_suffixed_with_the_first_free_counter() -> Result<()> {
+		let slug = SlugName::parse("shed")?;
+		assert_eq!(slug.deduplicate(["shed"]).as_ref(), "shed-2");
+		assert_eq!(slug.deduplicate(["shed", "shed-2", "shed-4"]).as_ref(), "shed-3");
+
+		Ok(())
+	}
+}
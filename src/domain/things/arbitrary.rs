@@ -0,0 +1,60 @@
+//! Random strings for property testing the Thing newtype parsers
+//!
+//! Each generator takes the test's seeded `rng`, so a failing case can be
+//! reproduced, and builds strings from `units` of at most a few chars. A unit
+//! extends the previous grapheme at most, so a string of `n` units is never
+//! more than `n` graphemes.
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+/// Cases generated by each property test
+pub const CASES: usize = 256;
+
+/// Characters the Thing parsers reject
+pub const FORBIDDEN_CHARACTERS: [char; 9] = ['/', '(', ')', '"', '<', '>', '\\', '{', '}'];
+
+/// Unicode whitespace, including the non ASCII spaces `trim` removes
+const WHITESPACE: [&str; 8] = [" ", "\t", "\n", "\r", "\r\n", "\u{a0}", "\u{2003}", "\u{3000}"];
+
+/// Allowed non whitespace units, including combining characters and multi
+/// char graphemes
+const CONTENT: [&str; 14] = [
+	"a", "Z", "7", "-", "&", "é", "e\u{301}", "a\u{310}", "\u{301}", "ß", "漢", "👍", "👩‍💻", "🇦🇺",
+];
+
+/// A reproducible random number generator for `test`
+pub fn rng(test: &str) -> StdRng {
+	StdRng::seed_from_u64(test.bytes().map(u64::from).sum())
+}
+
+/// A string of up to `max_units` whitespace units
+pub fn whitespace(rng: &mut StdRng, max_units: usize) -> String {
+	let units = rng.gen_range(0..=max_units);
+	(0..units).map(|_| *WHITESPACE.choose(rng).unwrap()).collect()
+}
+
+/// A string of allowed units with at least one non whitespace unit, at most
+/// `max_graphemes` graphemes long
+pub fn valid(rng: &mut StdRng, max_graphemes: usize) -> String {
+	let units = rng.gen_range(1..=max_graphemes);
+	let content_at = rng.gen_range(0..units);
+	(0..units)
+		.map(|unit| {
+			if unit == content_at || rng.gen_bool(0.8) {
+				*CONTENT.choose(rng).unwrap()
+			} else {
+				*WHITESPACE.choose(rng).unwrap()
+			}
+		})
+		.collect()
+}
+
+/// A valid string with a forbidden character inserted at a random position
+pub fn with_forbidden_character(rng: &mut StdRng, max_graphemes: usize) -> String {
+	let mut chars: Vec<char> = valid(rng, max_graphemes).chars().collect();
+	let position = rng.gen_range(0..=chars.len());
+	chars.insert(position, *FORBIDDEN_CHARACTERS.choose(rng).unwrap());
+	chars.into_iter().collect()
+}
@@ -1,23 +1,43 @@
 // #![allow(unused)] // For development only
 
+#[cfg(test)]
+mod arbitrary;
+mod new_thing;
 mod thing_description;
 mod thing_name;
+mod thing_row;
+mod thing_sort;
+mod thing_status;
 
+pub use new_thing::NewThing;
 pub use thing_description::ThingDescription;
 pub use thing_name::ThingName;
+pub use thing_row::ThingRow;
+pub use thing_sort::ThingSort;
+pub use thing_status::ThingStatus;
 
 use chrono::prelude::*;
 use uuid::Uuid;
 
+use crate::domain::{timestamp, uuid_from_datetime, SlugName};
 use crate::prelude::*;
 // extern crate derive_more;
 
+/// The user id recorded as the creator and updater of Things until requests
+/// are authenticated
+pub const SYSTEM_USER_ID: Uuid = Uuid::nil();
+
 /// A Thing struct model.
 ///
 /// This struct contains the data model for Thing. The model should be consistent
 /// with the database table model. The database table models are defined in the
 /// folder `./migrations` using sql statements.
 ///
+/// Timestamps are always UTC, stored in `TIMESTAMP WITH TIME ZONE` columns. Use
+/// `ClientTimeZone` to render them in another time zone. They are truncated to
+/// the millisecond, see `timestamp`, so a Thing round trips through json and the
+/// database unchanged.
+///
 /// # References
 ///
 /// * [Module sqlx::postgres::types](https://docs.rs/sqlx/latest/sqlx/postgres/types/index.html)
@@ -38,12 +58,33 @@ pub struct Thing {
 	/// The Thing `description` is a String that can be null with the database,
 	/// so it is Optional within the struct.
 	pub description: Option<ThingDescription>,
+	/// The Thing `slug` is a URL friendly, unique version of the name. It is
+	/// generated by the services when the Thing is stored, so it is Optional.
+	pub slug: Option<SlugName>,
+	/// The Thing `status` cannot be null in the database and defaults to
+	/// `Active`.
+	pub status: ThingStatus,
 	/// The Thing `created_at` is a time zone time stamp and cannot be null in
 	/// the database.
+	#[serde(with = "crate::domain::timestamp")]
 	pub created_at: DateTime<Utc>,
 	/// The Thing `updated_at` is a time zone time stamp and cannot be null in
 	/// the database.
+	#[serde(with = "crate::domain::timestamp")]
 	pub updated_at: DateTime<Utc>,
+	/// The Thing `deleted_at` is a time zone time stamp set when the Thing is
+	/// soft deleted, so it is Optional.
+	#[serde(with = "crate::domain::timestamp::option")]
+	pub deleted_at: Option<DateTime<Utc>>,
+	/// The Thing `version` is an optimistic lock, incremented by the database
+	/// on every update. It cannot be null in the database and starts at `1`.
+	pub version: i32,
+	/// The Thing `created_by` is the id of the user who created it, and cannot
+	/// be null in the database.
+	pub created_by: Uuid,
+	/// The Thing `updated_by` is the id of the user who last updated it, and
+	/// cannot be null in the database.
+	pub updated_by: Uuid,
 }
 
 /// Implementation of the default Thing for creating a new thing.
@@ -55,8 +96,14 @@ impl Default for Thing {
 			id: Uuid::now_v7(),
 			name: ThingName::default(),
 			description: Some(ThingDescription::default()),
-			created_at: Utc::now(),
-			updated_at: Utc::now(),
+			slug: None,
+			status: ThingStatus::default(),
+			created_at: timestamp::truncate(Utc::now()),
+			updated_at: timestamp::truncate(Utc::now()),
+			deleted_at: None,
+			version: 1,
+			created_by: SYSTEM_USER_ID,
+			updated_by: SYSTEM_USER_ID,
 		}
 	}
 }
@@ -67,8 +114,12 @@ pub struct ThingBuilder {
 	id: Option<Uuid>,
 	name: Option<ThingName>,
 	description: Option<ThingDescription>,
+	status: ThingStatus,
 	created_at: Option<DateTime<Utc>>,
 	updated_at: Option<DateTime<Utc>>,
+	version: i32,
+	created_by: Uuid,
+	updated_by: Uuid,
 }
 
 impl ThingBuilder {
@@ -78,8 +129,12 @@ impl ThingBuilder {
 			id: Some(Uuid::new_v7(uuid::Timestamp::now(uuid::NoContext))),
 			name: Some(name),
 			description: None,
+			status: ThingStatus::default(),
 			created_at: Some(Utc::now()),
 			updated_at: Some(Utc::now()),
+			version: 1,
+			created_by: SYSTEM_USER_ID,
+			updated_by: SYSTEM_USER_ID,
 		}
 	}
 
@@ -91,16 +146,7 @@ impl ThingBuilder {
 
 	/// Overwrite default `id` ind builder with a DateTime<Utc> generated Uuid.
 	pub fn id_set_date_time(&mut self, date_time: DateTime<Utc>) -> &mut Self {
-		// println!("{date_time:#?}");
-		let uuid_timestamp: uuid::Timestamp = uuid::Timestamp::from_unix(
-			uuid::NoContext,
-			date_time.timestamp() as u64,
-			date_time.timestamp_nanos_opt().unwrap() as u32,
-		);
-		// println!("{uuid_timestamp:#?}");
-		let id: Uuid = Uuid::new_v7(uuid_timestamp);
-		// println!("{id:#?}");
-		let _ = self.id.insert(id);
+		let _ = self.id.insert(uuid_from_datetime(date_time));
 		self
 	}
 
@@ -116,6 +162,12 @@ impl ThingBuilder {
 		self
 	}
 
+	/// Overwrite default `Active` status in builder.
+	pub fn status(&mut self, status: ThingStatus) -> &mut Self {
+		self.status = status;
+		self
+	}
+
 	/// Overwrite default `created_at` in builder.
 	pub fn created_at(&mut self, created_at: DateTime<Utc>) -> &mut Self {
 		let _ = self.created_at.insert(created_at);
@@ -128,6 +180,25 @@ impl ThingBuilder {
 		self
 	}
 
+	/// Overwrite default `1` version in builder, i.e. with the version an
+	/// update is based on.
+	pub fn version(&mut self, version: i32) -> &mut Self {
+		self.version = version;
+		self
+	}
+
+	/// Overwrite default `SYSTEM_USER_ID` creator in builder.
+	pub fn created_by(&mut self, created_by: Uuid) -> &mut Self {
+		self.created_by = created_by;
+		self
+	}
+
+	/// Overwrite default `SYSTEM_USER_ID` updater in builder.
+	pub fn updated_by(&mut self, updated_by: Uuid) -> &mut Self {
+		self.updated_by = updated_by;
+		self
+	}
+
 	pub fn build(&self) -> Result<Thing> {
 		// Run time check that `id` is not null
 		let Some(id) = self.id else {
@@ -153,8 +224,14 @@ impl ThingBuilder {
 			id,
 			name: name.clone(),
 			description: self.description.clone(),
-			created_at,
-			updated_at,
+			slug: None,
+			status: self.status,
+			created_at: timestamp::truncate(created_at),
+			updated_at: timestamp::truncate(updated_at),
+			deleted_at: None,
+			version: self.version,
+			created_by: self.created_by,
+			updated_by: self.updated_by,
 		})
 	}
 }
@@ -240,8 +317,9 @@ pub mod tests {
 		assert_eq!(test_new_thing.id, thing_id);
 		assert_eq!(test_new_thing.name, thing_name);
 		assert_eq!(test_new_thing.description.unwrap(), thing_description);
-		assert_eq!(test_new_thing.created_at, thing_created_at);
-		assert_eq!(test_new_thing.updated_at, thing_updated_at);
+		// Timestamps are held to the millisecond
+		assert_eq!(test_new_thing.created_at, timestamp::truncate(thing_created_at));
+		assert_eq!(test_new_thing.updated_at, timestamp::truncate(thing_updated_at));
 
 		Ok(())
 	}
@@ -271,8 +349,56 @@ pub mod tests {
 		//-- Checks (Assertions)
 		assert_eq!(test_new_thing.name, thing_name);
 		assert_eq!(test_new_thing.description.unwrap(), thing_description);
-		assert_eq!(test_new_thing.created_at, thing_created_at);
-		assert_eq!(test_new_thing.updated_at, thing_updated_at);
+		// Timestamps are held to the millisecond
+		assert_eq!(test_new_thing.created_at, timestamp::truncate(thing_created_at));
+		assert_eq!(test_new_thing.updated_at, timestamp::truncate(thing_updated_at));
+
+		Ok(())
+	}
+
+	// Test creating a new Thing records the system user until one is set
+	#[actix_rt::test]
+	async fn create_new_thing_with_audit_users() -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+        let name: String = Word().fake();
+		let thing_name = ThingName::parse(name)?;
+		let user_id = Uuid::now_v7();
+
+		//-- Execute Function (Act)
+		let default_thing = ThingBuilder::new(thing_name.clone()).build()?;
+		let test_new_thing = ThingBuilder::new(thing_name)
+			.created_by(user_id)
+			.updated_by(user_id)
+			.build()?;
+
+		//-- Checks (Assertions)
+		assert_eq!(default_thing.created_by, SYSTEM_USER_ID);
+		assert_eq!(default_thing.updated_by, SYSTEM_USER_ID);
+		assert_eq!(test_new_thing.created_by, user_id);
+		assert_eq!(test_new_thing.updated_by, user_id);
+
+		Ok(())
+	}
+
+	// Test a Thing with sub millisecond timestamps round trips through json
+	#[test]
+	fn thing_round_trips_through_json() -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let name: String = Word().fake();
+		let created_at: chrono::DateTime<Utc> = DateTime().fake();
+		let updated_at = created_at + chrono::Duration::nanoseconds(123_456_789);
+		let mut thing = ThingBuilder::new(ThingName::parse(name)?)
+			.created_at(created_at)
+			.updated_at(updated_at)
+			.build()?;
+		thing.deleted_at = Some(timestamp::truncate(updated_at));
+
+		//-- Execute Function (Act)
+		let json = serde_json::to_string(&thing)?;
+		let parsed: Thing = serde_json::from_str(&json)?;
+
+		//-- Checks (Assertions)
+		assert_eq!(parsed, thing);
 
 		Ok(())
 	}
@@ -0,0 +1,13 @@
+use crate::domain::{ThingDescription, ThingName};
+
+/// A Thing to be created, with validated fields but no id or timestamps yet
+///
+/// The description is optional, `None` is stored as a `NULL` column.
+///
+/// Handlers convert their form data into a `NewThing`, so every field is parsed
+/// into its newtype before a `Thing` is built.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NewThing {
+	pub name: ThingName,
+	pub description: Option<ThingDescription>,
+}
@@ -27,10 +27,22 @@ impl Default for ThingDescription {
 }
 
 impl ThingDescription {
+    /// The default maximum length of a description, in graphemes
+    pub const MAX_GRAPHEMES: usize = 256;
+
     /// Returns an instance of `ThingDescription` if the input satisfies all
     /// our validation constraints on subscriber names.
     /// It panics otherwise.
     pub fn parse(description: impl Into<String>) -> Result<ThingDescription> {
+        Self::parse_with_limit(description, Self::MAX_GRAPHEMES)
+    }
+
+    /// Parse a `ThingDescription` of at most `max_graphemes`, for deployments
+    /// with a different column size to `MAX_GRAPHEMES`
+    pub fn parse_with_limit(
+        description: impl Into<String>,
+        max_graphemes: usize,
+    ) -> Result<ThingDescription> {
         let description: String = description.into();
 
         // `.trim()` returns a view over the input `s` without trailing
@@ -45,7 +57,7 @@ impl ThingDescription {
         // `graphemes` returns an iterator over the graphemes in the input `s`.
         // `true` specifies that we want to use the extended grapheme definition set,
         // the recommended one.
-        let is_too_long = description.graphemes(true).count() > 256;
+        let is_too_long = description.graphemes(true).count() > max_graphemes;
 
         // Iterate over all characters in the input `s` to check if any of them matches
         // one of the characters in the forbidden array.
@@ -60,6 +72,20 @@ impl ThingDescription {
     }
 }
 
+impl std::fmt::Display for ThingDescription {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::str::FromStr for ThingDescription {
+    type Err = Error;
+
+    fn from_str(description: &str) -> Result<Self> {
+        Self::parse(description)
+    }
+}
+
 // impl AsRef<str> for ThingDescription {
 //     fn as_ref(&self) -> &str {
 //         &self.0
@@ -68,6 +94,7 @@ impl ThingDescription {
 
 #[cfg(test)]
 mod tests {
+    use crate::domain::things::arbitrary;
     use crate::domain::ThingDescription;
     use claim::{assert_err, assert_ok};
 
@@ -109,6 +136,24 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn the_default_limit_is_256_graphemes() {
+        assert_eq!(ThingDescription::MAX_GRAPHEMES, 256);
+    }
+
+    #[test]
+    fn a_description_longer_than_a_custom_limit_is_rejected() -> Result<()> {
+        assert_ok!(ThingDescription::parse_with_limit("a".repeat(64), 64));
+        assert!(
+            matches!(
+                ThingDescription::parse_with_limit("a".repeat(65), 64),
+                Err(crate::error::Error::ThingDescriptionValidationError {..} )
+            )
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn whitespace_only_names_are_rejected() -> Result<()> {
         let description = " ".to_string();
@@ -150,6 +195,18 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn a_description_round_trips_through_a_string() -> Result<()> {
+        let description: String = Sentence(3..7).fake();
+        let thing_description = ThingDescription::parse(description.clone())?;
+
+        assert_eq!(thing_description.to_string(), description);
+        assert_eq!(thing_description.to_string().parse::<ThingDescription>()?, thing_description);
+        assert_err!("".parse::<ThingDescription>());
+
+        Ok(())
+    }
+
     #[test]
     fn a_valid_name_is_parsed_successfully() -> Result<()> {
         let description: String = Sentence(3..7).fake();
@@ -157,4 +214,38 @@ mod tests {
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn any_description_containing_a_forbidden_character_is_rejected() {
+        let mut rng = arbitrary::rng("thing_description_forbidden");
+        for _ in 0..arbitrary::CASES {
+            let description =
+                arbitrary::with_forbidden_character(&mut rng, ThingDescription::MAX_GRAPHEMES);
+            assert!(
+                matches!(
+                    ThingDescription::parse(description.clone()),
+                    Err(crate::error::Error::ThingDescriptionValidationError { .. })
+                ),
+                "{description:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn any_whitespace_only_description_is_rejected() {
+        let mut rng = arbitrary::rng("thing_description_whitespace");
+        for _ in 0..arbitrary::CASES {
+            let description = arbitrary::whitespace(&mut rng, 16);
+            assert_err!(ThingDescription::parse(description.clone()), "{description:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn any_description_of_allowed_graphemes_within_the_limit_is_accepted() {
+        let mut rng = arbitrary::rng("thing_description_valid");
+        for _ in 0..arbitrary::CASES {
+            let description = arbitrary::valid(&mut rng, ThingDescription::MAX_GRAPHEMES);
+            assert_ok!(ThingDescription::parse(description.clone()), "{description:?} should be accepted");
+        }
+    }
+}
@@ -3,6 +3,10 @@ use unicode_segmentation::UnicodeSegmentation;
 use crate::prelude::*;
 extern crate derive_more;
 
+/// Names a Thing can not have, compared case insensitively, as they would be
+/// confused with the system's own records
+pub const RESERVED_THING_NAMES: &[&str] = &["admin", "system"];
+
 #[derive(
 	Clone,
 	serde::Deserialize,
@@ -28,16 +32,30 @@ impl Default for ThingName {
 impl ThingName {
 	// type Error = Error;
 
+	/// The default maximum length of a name, in graphemes
+	pub const MAX_GRAPHEMES: usize = 256;
+
 	/// Returns an instance of `ThingName` if the input satisfies all
 	/// our validation constraints on subscriber names.
 	/// It panics otherwise.
+	///
+	/// Surrounding whitespace is trimmed and internal runs of whitespace are
+	/// collapsed to a single space, so "Coffee" and " Coffee " are the same name.
+	/// Case is preserved for display, see `canonical` for comparisons.
 	pub fn parse(name: impl Into<String>) -> Result<ThingName> {
+		Self::parse_with_limit(name, Self::MAX_GRAPHEMES)
+	}
+
+	/// Parse a `ThingName` of at most `max_graphemes`, for deployments with a
+	/// different column size to `MAX_GRAPHEMES`
+	pub fn parse_with_limit(name: impl Into<String>, max_graphemes: usize) -> Result<ThingName> {
         let name: String = name.into();
+		let normalized = name.split_whitespace().collect::<Vec<_>>().join(" ");
 
 		// `.trim()` returns a view over the input `s` without trailing
 		// whitespace-like characters.
 		// `.is_empty` checks if the view contains any character.
-		let is_empty_or_whitespace = name.trim().is_empty();
+		let is_empty_or_whitespace = normalized.is_empty();
 
 		// A grapheme is defined by the Unicode standard as a "user-perceived"
 		// character: `å` is a single grapheme, but it is composed of two characters
@@ -46,7 +64,7 @@ impl ThingName {
 		// `graphemes` returns an iterator over the graphemes in the input `s`.
 		// `true` specifies that we want to use the extended grapheme definition set,
 		// the recommended one.
-		let is_too_long = name.graphemes(true).count() > 256;
+		let is_too_long = normalized.graphemes(true).count() > max_graphemes;
 
 		// Iterate over all characters in the input `s` to check if any of them matches
 		// one of the characters in the forbidden array.
@@ -56,10 +74,37 @@ impl ThingName {
 
 		if is_empty_or_whitespace || is_too_long || contains_forbidden_characters {
 			// Err(Error::Generic(format!("{} is not a valid thing name.", name)))
-			Err(Error::ThingNameValidationError { name })
-		} else {
-			Ok(Self(name))
+			return Err(Error::ThingNameValidationError { name });
+		}
+
+		let is_reserved = RESERVED_THING_NAMES
+			.iter()
+			.any(|reserved| normalized.eq_ignore_ascii_case(reserved));
+		if is_reserved {
+			return Err(Error::ThingNameReserved { name });
 		}
+
+		Ok(Self(normalized))
+	}
+
+	/// The lowercased name, used to compare names case insensitively as the
+	/// `things` unique name index does
+	pub fn canonical(&self) -> String {
+		self.0.to_lowercase()
+	}
+}
+
+impl std::fmt::Display for ThingName {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self.0)
+	}
+}
+
+impl std::str::FromStr for ThingName {
+	type Err = Error;
+
+	fn from_str(name: &str) -> Result<Self> {
+		Self::parse(name)
 	}
 }
 
@@ -69,6 +114,7 @@ mod tests {
 	pub type Result<T> = core::result::Result<T, Error>;
 	pub type Error = Box<dyn std::error::Error>;
 
+	use crate::domain::things::arbitrary;
 	use crate::domain::ThingName;
 	use claim::{assert_err, assert_ok};
 	use fake::faker::name::en::Name;
@@ -105,6 +151,24 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn the_default_limit_is_256_graphemes() {
+		assert_eq!(ThingName::MAX_GRAPHEMES, 256);
+	}
+
+	#[test]
+	fn a_name_longer_than_a_custom_limit_is_rejected() -> Result<()> {
+		assert_ok!(ThingName::parse_with_limit("a".repeat(32), 32));
+		assert!(
+			matches!(
+                ThingName::parse_with_limit("a".repeat(33), 32),
+                Err(crate::error::Error::ThingNameValidationError {..} )
+            )
+		);
+
+		Ok(())
+	}
+
 	#[test]
 	fn whitespace_only_names_are_rejected() -> Result<()> {
 		let name = " ".to_string();
@@ -147,6 +211,63 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn reserved_names_are_rejected() {
+		for name in ["admin", "System", " ADMIN "] {
+			assert!(
+				matches!(
+					ThingName::parse(name),
+					Err(crate::error::Error::ThingNameReserved { .. })
+				),
+				"{name:?} is reserved"
+			);
+		}
+	}
+
+	#[test]
+	fn names_containing_a_reserved_word_are_valid() {
+		assert_ok!(ThingName::parse("System Administrator"));
+		assert_ok!(ThingName::parse("admins"));
+	}
+
+	#[test]
+	fn surrounding_whitespace_is_trimmed() -> Result<()> {
+		let name = ThingName::parse("  Coffee \t")?;
+		assert_eq!(name.as_ref(), "Coffee");
+
+		Ok(())
+	}
+
+	#[test]
+	fn internal_whitespace_is_collapsed() -> Result<()> {
+		let name = ThingName::parse("Morning \t  Coffee\nBeans")?;
+		assert_eq!(name.as_ref(), "Morning Coffee Beans");
+
+		Ok(())
+	}
+
+	#[test]
+	fn canonical_is_case_insensitive() -> Result<()> {
+		let name = ThingName::parse("Coffee")?;
+		let other = ThingName::parse("coffee ")?;
+		assert_eq!(name.as_ref(), "Coffee");
+		assert_eq!(name.canonical(), other.canonical());
+
+		Ok(())
+	}
+
+	#[test]
+	fn a_name_round_trips_through_a_string() -> Result<()> {
+		let name: String = Name().fake();
+		let thing_name = ThingName::parse(name.clone())?;
+
+		assert_eq!(thing_name.to_string(), name);
+		assert_eq!(thing_name.to_string().parse::<ThingName>()?, thing_name);
+		assert_err!("admin".parse::<ThingName>());
+
+		Ok(())
+	}
+
 	#[test]
 	fn a_valid_name_is_parsed_successfully() -> Result<()> {
 		let name: String = Name().fake();
@@ -154,4 +275,37 @@ mod tests {
 
 		Ok(())
 	}
+
+	#[test]
+	fn any_name_containing_a_forbidden_character_is_rejected() {
+		let mut rng = arbitrary::rng("thing_name_forbidden");
+		for _ in 0..arbitrary::CASES {
+			let name = arbitrary::with_forbidden_character(&mut rng, ThingName::MAX_GRAPHEMES);
+			assert!(
+				matches!(
+					ThingName::parse(name.clone()),
+					Err(crate::error::Error::ThingNameValidationError { .. })
+				),
+				"{name:?} should be rejected"
+			);
+		}
+	}
+
+	#[test]
+	fn any_whitespace_only_name_is_rejected() {
+		let mut rng = arbitrary::rng("thing_name_whitespace");
+		for _ in 0..arbitrary::CASES {
+			let name = arbitrary::whitespace(&mut rng, 16);
+			assert_err!(ThingName::parse(name.clone()), "{name:?} should be rejected");
+		}
+	}
+
+	#[test]
+	fn any_name_of_allowed_graphemes_within_the_limit_is_accepted() {
+		let mut rng = arbitrary::rng("thing_name_valid");
+		for _ in 0..arbitrary::CASES {
+			let name = arbitrary::valid(&mut rng, ThingName::MAX_GRAPHEMES);
+			assert_ok!(ThingName::parse(name.clone()), "{name:?} should be accepted");
+		}
+	}
 }
@@ -0,0 +1,118 @@
+use chrono::prelude::*;
+use uuid::Uuid;
+
+use crate::domain::{timestamp, SlugName, Thing, ThingDescription, ThingName, ThingStatus};
+use crate::prelude::*;
+
+/// A `things` database table row.
+///
+/// Services query rows into this struct, then convert it into a `Thing`, which
+/// validates the name and description. Columns added to the table only need to
+/// be added here and in the conversion.
+#[derive(Clone, Debug, PartialEq, sqlx::FromRow)]
+pub struct ThingRow {
+	pub id: Uuid,
+	pub name: String,
+	/// The description column can be null in the database
+	pub description: Option<String>,
+	/// The slug column can be null in the database
+	pub slug: Option<String>,
+	pub status: String,
+	pub created_at: DateTime<Utc>,
+	pub updated_at: DateTime<Utc>,
+	pub deleted_at: Option<DateTime<Utc>>,
+	pub version: i32,
+	pub created_by: Uuid,
+	pub updated_by: Uuid,
+}
+
+impl TryFrom<ThingRow> for Thing {
+	type Error = Error;
+
+	fn try_from(row: ThingRow) -> Result<Self> {
+		Ok(Thing {
+			id: row.id,
+			name: ThingName::parse(row.name)?,
+			description: row.description.map(ThingDescription::parse).transpose()?,
+			slug: row.slug.map(SlugName::parse).transpose()?,
+			status: ThingStatus::try_from(row.status)?,
+			created_at: timestamp::truncate(row.created_at),
+			updated_at: timestamp::truncate(row.updated_at),
+			deleted_at: row.deleted_at.map(timestamp::truncate),
+			version: row.version,
+			created_by: row.created_by,
+			updated_by: row.updated_by,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// Override with more flexible error
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>;
+
+	// Bring module functions into test scope
+	use super::*;
+
+	use fake::faker::{chrono::en::DateTime, lorem::en::*};
+	use fake::Fake;
+
+	#[test]
+	fn thing_row_with_null_description_converts() -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let row = ThingRow {
+			id: Uuid::now_v7(),
+			name: Word().fake(),
+			description: None,
+			slug: None,
+			status: "active".to_string(),
+			created_at: DateTime().fake(),
+			updated_at: DateTime().fake(),
+			deleted_at: None,
+			version: 1,
+			created_by: Uuid::nil(),
+			updated_by: Uuid::nil(),
+		};
+
+		//-- Execute Function (Act)
+		let thing = Thing::try_from(row.clone())?;
+
+		//-- Checks (Assertions)
+		assert_eq!(thing.id, row.id);
+		assert_eq!(thing.name, ThingName::parse(row.name)?);
+		assert_eq!(thing.description, None);
+		assert_eq!(thing.status, ThingStatus::Active);
+		assert_eq!(thing.created_at, timestamp::truncate(row.created_at));
+		assert_eq!(thing.updated_at, timestamp::truncate(row.updated_at));
+
+		Ok(())
+	}
+
+	#[test]
+	fn thing_row_with_invalid_name_is_rejected() {
+		//-- Setup and Fixtures (Arrange)
+		let row = ThingRow {
+			id: Uuid::now_v7(),
+			name: "".to_string(),
+			description: Some(Sentence(3..7).fake()),
+			slug: None,
+			status: "active".to_string(),
+			created_at: DateTime().fake(),
+			updated_at: DateTime().fake(),
+			deleted_at: None,
+			version: 1,
+			created_by: Uuid::nil(),
+			updated_by: Uuid::nil(),
+		};
+
+		//-- Execute Function (Act)
+		let thing = Thing::try_from(row);
+
+		//-- Checks (Assertions)
+		assert!(matches!(
+			thing,
+			Err(crate::error::Error::ThingNameValidationError { .. })
+		));
+	}
+}
@@ -0,0 +1,27 @@
+/// Sort orders for indexing Things.
+///
+/// Deserialised from the `sort` URL parameter, i.e. `?sort=name_asc`. Each
+/// variant maps to a static `ORDER BY` clause, so user input is never
+/// interpolated into the sql statement.
+#[derive(Clone, Copy, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThingSort {
+	NameAsc,
+	NameDesc,
+	CreatedAtAsc,
+	#[default]
+	CreatedAtDesc,
+}
+
+impl ThingSort {
+	/// Returns the sql `ORDER BY` clause for the sort order, with `id` as a tie
+	/// breaker so pages are stable.
+	pub fn order_by(&self) -> &'static str {
+		match self {
+			Self::NameAsc => "ORDER BY name ASC, id ASC",
+			Self::NameDesc => "ORDER BY name DESC, id DESC",
+			Self::CreatedAtAsc => "ORDER BY created_at ASC, id ASC",
+			Self::CreatedAtDesc => "ORDER BY created_at DESC, id DESC",
+		}
+	}
+}
@@ -0,0 +1,72 @@
+use crate::prelude::*;
+
+/// The status of a Thing.
+///
+/// Archived Things are hidden from the default index, but unlike a delete they
+/// are kept and can be made active again. Deserialised from the `status` URL
+/// parameter, i.e. `?status=archived`.
+#[derive(
+	Clone,
+	Copy,
+	Debug,
+	Default,
+	PartialEq,
+	serde::Deserialize,
+	serde::Serialize,
+	sqlx::Type,
+)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "varchar", rename_all = "snake_case")]
+pub enum ThingStatus {
+	#[default]
+	Active,
+	Archived,
+}
+
+impl ThingStatus {
+	/// Returns the status as stored in the database `status` column
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::Active => "active",
+			Self::Archived => "archived",
+		}
+	}
+}
+
+impl TryFrom<String> for ThingStatus {
+	type Error = Error;
+
+	fn try_from(status: String) -> Result<Self> {
+		match status.as_str() {
+			"active" => Ok(Self::Active),
+			"archived" => Ok(Self::Archived),
+			_ => Err(Error::ThingStatusValidationError { status }),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// Override with more flexible error
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>;
+
+	use crate::domain::ThingStatus;
+
+	#[test]
+	fn thing_status_round_trips_as_str() -> Result<()> {
+		for status in [ThingStatus::Active, ThingStatus::Archived] {
+			assert_eq!(ThingStatus::try_from(status.as_str().to_string())?, status);
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn unknown_status_is_rejected() {
+		assert!(matches!(
+			ThingStatus::try_from("deleted".to_string()),
+			Err(crate::error::Error::ThingStatusValidationError { .. })
+		));
+	}
+}
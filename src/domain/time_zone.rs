@@ -0,0 +1,134 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use chrono_tz::Tz;
+
+use crate::prelude::*;
+
+/// Json fields holding a timestamp, rendered in the client time zone by
+/// `ClientTimeZone::localise`
+const TIMESTAMP_FIELDS: &[&str] = &["created_at", "updated_at", "deleted_at"];
+
+/// A client requested time zone, for rendering timestamps at the edge
+///
+/// Timestamps are always stored and handled as `DateTime<Utc>`, the database
+/// columns being `TIMESTAMP WITH TIME ZONE`. They are only converted into a
+/// client time zone when rendered for a response.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ClientTimeZone(Tz);
+
+/// Implementation of the default time zone, UTC
+impl Default for ClientTimeZone {
+	fn default() -> Self {
+		Self(Tz::UTC)
+	}
+}
+
+impl ClientTimeZone {
+	/// Returns a `ClientTimeZone` for an IANA time zone name, i.e.
+	/// `Australia/Brisbane`, or a `TimeZoneValidationError` if the name is unknown.
+	pub fn parse(time_zone: impl Into<String>) -> Result<ClientTimeZone> {
+		let time_zone: String = time_zone.into();
+
+		match time_zone.trim().parse::<Tz>() {
+			Ok(tz) => Ok(Self(tz)),
+			Err(_) => Err(Error::TimeZoneValidationError { time_zone }),
+		}
+	}
+
+	/// Render a UTC `timestamp` in the time zone as an RFC 3339 string
+	pub fn render(&self, timestamp: &DateTime<Utc>) -> String {
+		timestamp
+			.with_timezone(&self.0)
+			.to_rfc3339_opts(SecondsFormat::Millis, false)
+	}
+
+	/// Serialise `value` as json with the timestamp fields, `created_at`,
+	/// `updated_at` and `deleted_at`, rendered in the time zone. Fields of
+	/// nested objects and arrays, i.e. a page of Things, are rendered too.
+	pub fn localise(&self, value: &impl serde::Serialize) -> Result<serde_json::Value> {
+		let mut value = serde_json::to_value(value)?;
+		self.localise_value(&mut value);
+		Ok(value)
+	}
+
+	fn localise_value(&self, value: &mut serde_json::Value) {
+		match value {
+			serde_json::Value::Array(values) => {
+				values.iter_mut().for_each(|value| self.localise_value(value));
+			}
+			serde_json::Value::Object(fields) => {
+				for (name, field) in fields.iter_mut() {
+					let timestamp = field
+						.as_str()
+						.filter(|_| TIMESTAMP_FIELDS.contains(&name.as_str()))
+						.and_then(|field| DateTime::parse_from_rfc3339(field).ok());
+					match timestamp {
+						Some(timestamp) => *field = self.render(&timestamp.with_timezone(&Utc)).into(),
+						None => self.localise_value(field),
+					}
+				}
+			}
+			_ => {}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// Override with more flexible error
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>;
+
+	use crate::domain::ClientTimeZone;
+	use chrono::{DateTime, TimeZone, Utc};
+	use claim::assert_err;
+
+	#[test]
+	fn a_utc_timestamp_renders_in_the_client_time_zone() -> Result<()> {
+		let timestamp: DateTime<Utc> = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+		let time_zone = ClientTimeZone::parse("Australia/Brisbane")?;
+
+		assert_eq!(time_zone.render(&timestamp), "2024-01-01T10:00:00.000+10:00");
+
+		Ok(())
+	}
+
+	#[test]
+	fn the_default_time_zone_renders_utc() {
+		let timestamp: DateTime<Utc> = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+		assert_eq!(
+			ClientTimeZone::default().render(&timestamp),
+			"2024-01-01T00:00:00.000+00:00"
+		);
+	}
+
+	#[test]
+	fn localise_renders_nested_timestamp_fields() -> Result<()> {
+		let time_zone = ClientTimeZone::parse("Australia/Brisbane")?;
+		let page = serde_json::json!({
+			"data": [{ "name": "2024-01-01T00:00:00.000Z", "created_at": "2024-01-01T00:00:00.000Z", "deleted_at": null }],
+			"total": 1,
+		});
+
+		let localised = time_zone.localise(&page)?;
+
+		assert_eq!(
+			localised,
+			serde_json::json!({
+				"data": [{ "name": "2024-01-01T00:00:00.000Z", "created_at": "2024-01-01T10:00:00.000+10:00", "deleted_at": null }],
+				"total": 1,
+			})
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn an_unknown_time_zone_is_rejected() {
+		assert_err!(ClientTimeZone::parse("Mars/Olympus_Mons"));
+		assert!(matches!(
+			ClientTimeZone::parse(""),
+			Err(crate::error::Error::TimeZoneValidationError { .. })
+		));
+	}
+}
@@ -0,0 +1,79 @@
+//! Millisecond precision Thing timestamps
+//!
+//! Postgres stores timestamps to the microsecond and chrono keeps nanoseconds,
+//! so a Thing read back from the database, or from its json, would otherwise
+//! not equal the Thing written. Things truncate their timestamps to the
+//! millisecond with `truncate`, and serialise them with the `serde` functions
+//! here as RFC 3339 strings with exactly three fractional digits, i.e.
+//! `2024-06-30T12:34:56.789Z`.
+//!
+//! Use `#[serde(with = "crate::domain::timestamp")]` on a `DateTime<Utc>` and
+//! `#[serde(with = "crate::domain::timestamp::option")]` on an
+//! `Option<DateTime<Utc>>`.
+
+use chrono::{DateTime, SecondsFormat, SubsecRound, Utc};
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// `date_time` truncated to the millisecond
+pub fn truncate(date_time: DateTime<Utc>) -> DateTime<Utc> {
+	date_time.trunc_subsecs(3)
+}
+
+/// Serialise `date_time` as an RFC 3339 string in milliseconds
+pub fn serialize<S: Serializer>(date_time: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error> {
+	serializer.serialize_str(&date_time.to_rfc3339_opts(SecondsFormat::Millis, true))
+}
+
+/// Deserialise an RFC 3339 string, truncating it to the millisecond
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateTime<Utc>, D::Error> {
+	let date_time = DateTime::<Utc>::deserialize(deserializer)?;
+	Ok(truncate(date_time))
+}
+
+/// Millisecond serde functions for an optional timestamp, `None` is `null`
+pub mod option {
+	use chrono::{DateTime, Utc};
+	use serde::{Deserialize, Deserializer, Serializer};
+
+	/// Serialise `date_time` as an RFC 3339 string in milliseconds, or `null`
+	pub fn serialize<S: Serializer>(date_time: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error> {
+		match date_time {
+			Some(date_time) => super::serialize(date_time, serializer),
+			None => serializer.serialize_none(),
+		}
+	}
+
+	/// Deserialise an optional RFC 3339 string, truncating it to the millisecond
+	pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error> {
+		let date_time = Option::<DateTime<Utc>>::deserialize(deserializer)?;
+		Ok(date_time.map(super::truncate))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use chrono::TimeZone;
+
+	#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+	struct Stamped {
+		#[serde(with = "crate::domain::timestamp")]
+		at: DateTime<Utc>,
+		#[serde(with = "crate::domain::timestamp::option")]
+		maybe: Option<DateTime<Utc>>,
+	}
+
+	#[test]
+	fn timestamps_serialize_in_milliseconds() -> Result<(), serde_json::Error> {
+		let at = Utc.with_ymd_and_hms(2024, 6, 30, 12, 34, 56).unwrap() + chrono::Duration::nanoseconds(789_123_456);
+		let stamped = Stamped { at, maybe: None };
+
+		let json = serde_json::to_value(&stamped)?;
+		let parsed: Stamped = serde_json::from_value(json.clone())?;
+
+		assert_eq!(json, serde_json::json!({ "at": "2024-06-30T12:34:56.789Z", "maybe": null }));
+		assert_eq!(parsed, Stamped { at: truncate(at), maybe: None });
+
+		Ok(())
+	}
+}
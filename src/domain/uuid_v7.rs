@@ -0,0 +1,43 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Generate a time ordered (v7) Uuid with the timestamp `date_time`, so
+/// records can be given an id matching when they were created.
+///
+/// A v7 Uuid holds milliseconds since the Unix epoch, so a `date_time` before
+/// the epoch is clamped to the epoch.
+pub fn uuid_from_datetime(date_time: DateTime<Utc>) -> Uuid {
+	let uuid_timestamp = uuid::Timestamp::from_unix(
+		uuid::NoContext,
+		date_time.timestamp().max(0) as u64,
+		date_time.timestamp_subsec_nanos(),
+	);
+
+	Uuid::new_v7(uuid_timestamp)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::domain::uuid_from_datetime;
+	use chrono::{DateTime, TimeZone, Utc};
+
+	#[test]
+	fn uuid_timestamp_matches_the_date_time() {
+		let date_time: DateTime<Utc> = Utc.with_ymd_and_hms(2024, 6, 30, 12, 34, 56).unwrap();
+
+		let uuid = uuid_from_datetime(date_time);
+
+		let (seconds, _nanos) = uuid.get_timestamp().unwrap().to_unix();
+		assert_eq!(seconds, date_time.timestamp() as u64);
+		assert_eq!(uuid.get_version_num(), 7);
+	}
+
+	#[test]
+	fn a_date_time_before_the_epoch_is_clamped() {
+		let date_time: DateTime<Utc> = Utc.with_ymd_and_hms(1969, 12, 31, 0, 0, 0).unwrap();
+
+		let uuid = uuid_from_datetime(date_time);
+
+		assert_eq!(uuid.get_timestamp().unwrap().to_unix(), (0, 0));
+	}
+}
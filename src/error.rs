@@ -5,7 +5,7 @@
 //! 
 //! Module (folder) error crates are imported through
 
-use actix_web::{http::StatusCode, ResponseError};
+use actix_web::{http::StatusCode, HttpResponse, ResponseError};
 
 /// Error static type enums
 /// 
@@ -26,36 +26,146 @@ pub enum Error {
 	Static(&'static str),
 
 	// -- Module Error Crates
-    // #[error(transparent)]
-	// Handlers(#[from] handlers::Error),
-
-	#[error("There is no Thing associated with the provided id.")]
-    ThingUnknownId,
-	#[error("There is no Thing associated with the provided name.")]
-    ThingUnknownName,
-	#[error("Parameter missing from query.")]
-	ParameterMissing,
+	#[error(transparent)]
+	Handlers(#[from] crate::handlers::Error),
+
+	#[error(transparent)]
+	Services(#[from] crate::services::Error),
+
+	// -- Domain validation
 	#[error("{name:?} is not a valid Thing name.")]
 	ThingNameValidationError {
 		name: String,
 	},
+	#[error("{name:?} is a reserved name, Things can not use it.")]
+	ThingNameReserved {
+		name: String,
+	},
 	#[error("{description:?} is not a valid Thing description.")]
 	ThingDescriptionValidationError {
 		description: String,
 	},
+	#[error("A Thing named {name:?} already exists.")]
+	ThingNameTaken {
+		name: String,
+	},
+	#[error("A Thing with the slug {slug:?} was created at the same time, retry the request.")]
+	ThingSlugTaken {
+		slug: String,
+	},
+	#[error("Thing {id} has been updated since version {version}.")]
+	StaleUpdate {
+		id: uuid::Uuid,
+		version: i32,
+	},
+	#[error("{status:?} is not a valid Thing status.")]
+	ThingStatusValidationError {
+		status: String,
+	},
+	#[error("{slug:?} can not be made into a slug.")]
+	SlugNameValidationError {
+		slug: String,
+	},
+	#[error("{body:?} is not a valid Note body.")]
+	NoteBodyValidationError {
+		body: String,
+	},
+	#[error("{time_zone:?} is not a valid time zone.")]
+	TimeZoneValidationError {
+		time_zone: String,
+	},
+	#[error("The form has {} invalid field(s).", errors.len())]
+	FormValidationError {
+		/// The validation error message of each invalid field, by field name
+		errors: std::collections::BTreeMap<String, String>,
+	},
 
-	// #[error(transparent)]
-	// Services(#[from] crate::personal_ledger_server::services::error::Error),
+	// -- Requests
+	#[error("Too many requests, retry after {retry_after_seconds} seconds.")]
+	RateLimited {
+		retry_after_seconds: u64,
+	},
 
 	// -- Externals
 	#[error(transparent)]
 	IO(#[from] std::io::Error),
 
+	#[error("The database query took too long and was cancelled.")]
+	DatabaseTimeout,
+
+	#[error("Database `require_ssl` is set, but the connection is not encrypted: {0}")]
+	DatabaseSslRequired(String),
+
+	#[error(transparent)]
+    Database(sqlx::Error),
+
 	#[error(transparent)]
-    Database(#[from] sqlx::Error),
+	Migrate(#[from] sqlx::migrate::MigrateError),
 
 	#[error(transparent)]
     Config(#[from] config::ConfigError),
+
+	#[error(transparent)]
+	Json(#[from] serde_json::Error),
+
+	#[error(transparent)]
+	EmailClient(reqwest::Error),
+}
+
+/// Postgres error code for a cancelled query, by `statement_timeout` or a
+/// cancel request
+const POSTGRES_QUERY_CANCELED: &str = "57014";
+
+/// Postgres error message of a query cancelled by `statement_timeout`, telling
+/// it apart from other cancels
+const POSTGRES_STATEMENT_TIMEOUT_MESSAGE: &str = "canceling statement due to statement timeout";
+
+// Convert sqlx errors, picking out statement timeouts
+impl From<sqlx::Error> for Error {
+	fn from(error: sqlx::Error) -> Self {
+		match &error {
+			sqlx::Error::Database(database_error)
+				if database_error.code().as_deref() == Some(POSTGRES_QUERY_CANCELED)
+					&& database_error.message() == POSTGRES_STATEMENT_TIMEOUT_MESSAGE =>
+			{
+				Self::DatabaseTimeout
+			}
+			_ => Self::Database(error),
+		}
+	}
+}
+
+impl Error {
+	/// The name of the error variant, returned to clients as a machine readable
+	/// `code` alongside the error message
+	pub fn code(&self) -> &'static str {
+		match self {
+			Self::Generic(_) => "Generic",
+			Self::Static(_) => "Static",
+			Self::Handlers(error) => error.code(),
+			Self::Services(error) => error.code(),
+			Self::ThingNameValidationError { .. } => "ThingNameValidationError",
+			Self::ThingNameReserved { .. } => "ThingNameReserved",
+			Self::ThingDescriptionValidationError { .. } => "ThingDescriptionValidationError",
+			Self::ThingNameTaken { .. } => "ThingNameTaken",
+			Self::ThingSlugTaken { .. } => "ThingSlugTaken",
+			Self::StaleUpdate { .. } => "StaleUpdate",
+			Self::ThingStatusValidationError { .. } => "ThingStatusValidationError",
+			Self::SlugNameValidationError { .. } => "SlugNameValidationError",
+			Self::NoteBodyValidationError { .. } => "NoteBodyValidationError",
+			Self::TimeZoneValidationError { .. } => "TimeZoneValidationError",
+			Self::FormValidationError { .. } => "FormValidationError",
+			Self::RateLimited { .. } => "RateLimited",
+			Self::IO(_) => "IO",
+			Self::DatabaseTimeout => "DatabaseTimeout",
+			Self::DatabaseSslRequired(_) => "DatabaseSslRequired",
+			Self::Database(_) => "Database",
+			Self::Migrate(_) => "Migrate",
+			Self::Config(_) => "Config",
+			Self::Json(_) => "Json",
+			Self::EmailClient(_) => "EmailClient",
+		}
+	}
 }
 
 // impl Error {
@@ -75,9 +185,124 @@ pub enum Error {
 impl ResponseError for Error {
     fn status_code(&self) -> StatusCode {
         match self {
-			Self::ThingUnknownName => StatusCode::BAD_REQUEST,
-            Self::ThingUnknownId => StatusCode::BAD_REQUEST,
+			Self::Handlers(crate::handlers::Error::ParameterMissing) => StatusCode::BAD_REQUEST,
+			Self::Handlers(crate::handlers::Error::ThingIdInvalid { .. }) => StatusCode::BAD_REQUEST,
+			Self::Handlers(crate::handlers::Error::ParameterNegative { .. }) => StatusCode::BAD_REQUEST,
+			Self::Handlers(crate::handlers::Error::PathParameterInvalid { .. }) => StatusCode::BAD_REQUEST,
+			Self::Handlers(crate::handlers::Error::MergePatchInvalid { .. }) => StatusCode::BAD_REQUEST,
+			Self::Handlers(crate::handlers::Error::UnsupportedMediaType { .. }) => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+			Self::ThingNameValidationError { .. } => StatusCode::BAD_REQUEST,
+			Self::ThingNameReserved { .. } => StatusCode::BAD_REQUEST,
+			Self::ThingDescriptionValidationError { .. } => StatusCode::BAD_REQUEST,
+			Self::ThingStatusValidationError { .. } => StatusCode::BAD_REQUEST,
+			Self::SlugNameValidationError { .. } => StatusCode::BAD_REQUEST,
+			Self::NoteBodyValidationError { .. } => StatusCode::BAD_REQUEST,
+			Self::TimeZoneValidationError { .. } => StatusCode::BAD_REQUEST,
+			Self::Services(crate::services::Error::ThingUnknownId) => StatusCode::NOT_FOUND,
+			Self::Services(crate::services::Error::ThingUnknownName) => StatusCode::NOT_FOUND,
+			Self::Services(crate::services::Error::ThingUnknownSlug) => StatusCode::NOT_FOUND,
+			Self::Services(crate::services::Error::NoteUnknownId) => StatusCode::NOT_FOUND,
+			Self::Services(crate::services::Error::UserUnknownId) => StatusCode::NOT_FOUND,
+			Self::Services(crate::services::Error::VerificationTokenInvalid) => StatusCode::BAD_REQUEST,
+			Self::Services(crate::services::Error::VerificationTokenExpired) => StatusCode::BAD_REQUEST,
+			Self::Services(crate::services::Error::ApiKeyInvalid) => StatusCode::UNAUTHORIZED,
+			Self::Services(crate::services::Error::IdempotencyKeyInProgress) => StatusCode::CONFLICT,
+			Self::Database(sqlx::Error::RowNotFound) => StatusCode::NOT_FOUND,
+			Self::FormValidationError { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+			Self::ThingNameTaken { .. } => StatusCode::CONFLICT,
+			Self::ThingSlugTaken { .. } => StatusCode::CONFLICT,
+			Self::StaleUpdate { .. } => StatusCode::CONFLICT,
+			Self::RateLimited { .. } => StatusCode::TOO_MANY_REQUESTS,
+			Self::DatabaseTimeout => StatusCode::GATEWAY_TIMEOUT,
 			_ => StatusCode::INTERNAL_SERVER_ERROR
         }
     }
-}
\ No newline at end of file
+
+	// Respond with a json body, so clients can tell errors apart by `code`
+	fn error_response(&self) -> HttpResponse {
+		let message = match self {
+			Self::Database(sqlx::Error::RowNotFound) => "not found".to_string(),
+			error => error.to_string(),
+		};
+
+		let mut response = HttpResponse::build(self.status_code());
+		if let Self::RateLimited { retry_after_seconds } = self {
+			response.insert_header((actix_web::http::header::RETRY_AFTER, *retry_after_seconds));
+		}
+
+		let mut body = serde_json::json!({ "error": message, "code": self.code() });
+		if let Self::FormValidationError { errors } = self {
+			body["errors"] = serde_json::json!(errors);
+		}
+
+		response.json(body)
+	}
+}
+
+//-- Unit Tests
+#[cfg(test)]
+pub mod tests {
+	// Bring module functions into test scope
+	use super::*;
+
+	#[test]
+	fn services_error_converts_preserving_message() {
+		//-- Setup and Fixtures (Arrange)
+		let services_error = crate::services::Error::ThingUnknownId;
+		let message = services_error.to_string();
+
+		//-- Execute Function (Act)
+		let error: Error = services_error.into();
+
+		//-- Checks (Assertions)
+		assert_eq!(error.to_string(), message);
+		assert_eq!(error.status_code(), StatusCode::NOT_FOUND);
+	}
+
+	#[test]
+	fn handlers_error_converts_preserving_message() {
+		//-- Setup and Fixtures (Arrange)
+		let handlers_error = crate::handlers::Error::ParameterMissing;
+		let message = handlers_error.to_string();
+
+		//-- Execute Function (Act)
+		let error: Error = handlers_error.into();
+
+		//-- Checks (Assertions)
+		assert_eq!(error.to_string(), message);
+		assert_eq!(error.status_code(), StatusCode::BAD_REQUEST);
+	}
+
+	#[test]
+	fn row_not_found_is_a_not_found_response() {
+		//-- Setup and Fixtures (Arrange)
+		let error: Error = sqlx::Error::RowNotFound.into();
+
+		//-- Execute Function (Act)
+		let response = error.error_response();
+
+		//-- Checks (Assertions)
+		assert_eq!(error.status_code(), StatusCode::NOT_FOUND);
+		assert_eq!(response.status(), StatusCode::NOT_FOUND);
+	}
+
+	#[actix_rt::test]
+	async fn error_response_is_a_json_message_and_code() {
+		//-- Setup and Fixtures (Arrange)
+		let error: Error = crate::handlers::Error::ParameterMissing.into();
+
+		//-- Execute Function (Act)
+		let response = error.error_response();
+		let body = actix_web::body::to_bytes(response.into_body()).await.unwrap();
+		let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+		//-- Checks (Assertions)
+		assert_eq!(
+			body,
+			serde_json::json!({
+				"error": "Parameter missing from query.",
+				"code": "ParameterMissing"
+			})
+		);
+	}
+}
@@ -9,14 +9,13 @@
 //! * `U`pdate implements `PUT/PATCH`
 //! * `D`elete implements `DELETE`
 //!
-use actix_web::{delete, get, post, put, HttpResponse, Responder};
+use actix_web::{HttpResponse, Responder};
 
 /// # CREATE (POST) COMPANY
 ///
 /// Create a Company record and respond with created instance
 ///
 #[tracing::instrument(name = "Create Company")]
-#[post("")]
 pub async fn create() -> impl Responder {
     HttpResponse::Ok().body("Create a Company record and respond its created instance...")
 }
@@ -26,7 +25,6 @@ pub async fn create() -> impl Responder {
 /// Return a Company by ID
 ///
 #[tracing::instrument(name = "Index Companies")]
-#[get("")]
 pub async fn index() -> impl Responder {
     HttpResponse::Ok().body("Respond with a list (index) of Companies...")
 }
@@ -36,7 +34,6 @@ pub async fn index() -> impl Responder {
 /// Return a Company by ID
 ///
 #[tracing::instrument(name = "Read Company")]
-#[get("{company_id}")]
 pub async fn read() -> impl Responder {
     HttpResponse::Ok().body("Find a Company by {company_id} and return instance...")
 }
@@ -46,7 +43,6 @@ pub async fn read() -> impl Responder {
 /// Find a Company by {company_id}, update and return instance
 ///
 #[tracing::instrument(name = "Update Company")]
-#[put("{company_id}")]
 pub async fn update() -> impl Responder {
     HttpResponse::Ok().body("Find a Company by {company_id}, update and return instance...")
 }
@@ -56,7 +52,6 @@ pub async fn update() -> impl Responder {
 /// Find a Company by {company_id}, update and return confirmation
 ///
 #[tracing::instrument(name = "Delete Company")]
-#[delete{"{company_id}"}]
 pub async fn delete() -> impl Responder {
     HttpResponse::Ok().body("Find a Company by {company_id}, update and return confirmation...")
 }
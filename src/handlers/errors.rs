@@ -1,25 +1,75 @@
-use actix_web::{http::StatusCode, ResponseError};
+//! Handlers module errors
+//!
+//! This is passed through to the main error crate, which implements the
+//! Actix `ResponseError` for all errors.
 
 /// Handlers module errors
 #[derive(thiserror::Error, Debug, PartialEq)]
 pub enum Error {
-	#[error("There is no Thing associated with the provided id.")]
-    ThingUnknownId,
-
-	#[error("There is no Thing associated with the provided name.")]
-    ThingUnknownName,
-
 	#[error("Parameter missing from query.")]
 	ParameterMissing,
+
+	#[error("{id:?} is not a valid Thing id.")]
+	ThingIdInvalid {
+		id: String,
+	},
+
+	#[error("{value:?} is not a valid `{name}` path parameter.")]
+	PathParameterInvalid {
+		name: String,
+		value: String,
+	},
+
+	#[error("{name} must not be negative, got {value}.")]
+	ParameterNegative {
+		name: &'static str,
+		value: i64,
+	},
+
+	#[error("{content_type:?} is not supported, use `{expected}`.")]
+	UnsupportedMediaType {
+		content_type: String,
+		expected: &'static str,
+	},
+
+	#[error("The merge patch is not a JSON object: {reason}")]
+	MergePatchInvalid {
+		reason: String,
+	},
+}
+
+impl Error {
+	/// The name of the error variant, used as the error `code` in responses
+	pub fn code(&self) -> &'static str {
+		match self {
+			Self::ParameterMissing => "ParameterMissing",
+			Self::ThingIdInvalid { .. } => "ThingIdInvalid",
+			Self::PathParameterInvalid { .. } => "PathParameterInvalid",
+			Self::ParameterNegative { .. } => "ParameterNegative",
+			Self::UnsupportedMediaType { .. } => "UnsupportedMediaType",
+			Self::MergePatchInvalid { .. } => "MergePatchInvalid",
+		}
+	}
 }
 
-impl ResponseError for Error {
-    fn status_code(&self) -> StatusCode {
-        let status_code = match self {
-			Self::ThingUnknownName => StatusCode::BAD_REQUEST,
-            Self::ThingUnknownId => StatusCode::BAD_REQUEST,
-            Self::ParameterMissing => StatusCode::BAD_REQUEST,
-        };
-        status_code
-    }
-}
\ No newline at end of file
+/// Actix `PathConfig` error handler, so a path parameter that does not
+/// deserialise is a json `PathParameterInvalid` error (`400 Bad Request`)
+/// rather than the plain text Actix default.
+///
+/// Path parameters are either Uuids or strings, and a string always
+/// deserialises, so the offending parameter is the first that is not a Uuid.
+pub fn path_error_handler(
+	error: actix_web::error::PathError,
+	request: &actix_web::HttpRequest,
+) -> actix_web::Error {
+	tracing::debug!("Path parameter error: {error}");
+
+	let (name, value) = request
+		.match_info()
+		.iter()
+		.find(|(_, value)| uuid::Uuid::parse_str(value).is_err())
+		.map(|(name, value)| (name.to_string(), value.to_string()))
+		.unwrap_or_else(|| ("path".to_string(), request.path().to_string()));
+
+	crate::error::Error::from(Error::PathParameterInvalid { name, value }).into()
+}
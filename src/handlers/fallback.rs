@@ -0,0 +1,95 @@
+//-- ./src/handlers/fallback.rs
+
+//! Fallback response handler, so unmatched requests get a JSON body
+//!
+//! # Fallback Handler
+//!
+//! `unmatched` is registered as the application `default_service`. Routes carry
+//! their method guard, so a known path requested with an unsupported method also
+//! falls through to here and is answered with a `405` rather than a `404`.
+
+use crate::api;
+
+use actix_web::dev::ResourceDef;
+use actix_web::http::header;
+use actix_web::{HttpRequest, HttpResponse};
+
+/// Default service handler for requests that do not match a route
+///
+/// Returns a `405` JSON body with an `Allow` header if the path is mounted for
+/// other methods, otherwise a `404` JSON body with the unmatched path. Like
+/// `Error` responses, the body has an `error` message and a `code`.
+pub async fn unmatched(request: HttpRequest) -> HttpResponse {
+	let path = request.path();
+	let allowed = allowed_methods(path);
+
+	if allowed.is_empty() {
+		HttpResponse::NotFound().json(serde_json::json!({
+			"error": "not found",
+			"code": "NOT_FOUND",
+			"path": path,
+		}))
+	} else {
+		HttpResponse::MethodNotAllowed()
+			.insert_header((header::ALLOW, allowed.join(", ")))
+			.json(serde_json::json!({
+				"error": "method not allowed",
+				"code": "METHOD_NOT_ALLOWED",
+				"path": path,
+			}))
+	}
+}
+
+/// Methods of the mounted routes matching `path`, without duplicates
+///
+/// A path matched by a static segment, i.e. `/things/count`, is not also
+/// matched by a dynamic segment, i.e. `/things/{thing_id}`, so only the routes
+/// with the fewest dynamic segments are used.
+fn allowed_methods(path: &str) -> Vec<&'static str> {
+	let matching: Vec<api::MountedRoute> = api::v1_routes()
+		.into_iter()
+		.chain(api::v2_routes())
+		.filter(|route| ResourceDef::new(route.path.as_str()).is_match(path))
+		.collect();
+	let dynamic_segments = |route: &api::MountedRoute| route.path.matches('{').count();
+	let Some(fewest) = matching.iter().map(dynamic_segments).min() else {
+		return Vec::new();
+	};
+
+	let mut allowed: Vec<&'static str> = Vec::new();
+	for route in matching.iter().filter(|route| dynamic_segments(route) == fewest) {
+		if !allowed.contains(&route.method) {
+			allowed.push(route.method);
+		}
+	}
+	allowed
+}
+
+//-- Unit Tests
+#[cfg(test)]
+pub mod tests {
+	// Bring module functions into test scope
+	use super::*;
+
+	#[test]
+	fn allowed_methods_of_a_mounted_path() {
+		assert_eq!(allowed_methods("/api/v1/things"), vec!["GET", "POST", "DELETE"]);
+		assert_eq!(
+			allowed_methods("/api/v1/things/0190b6f4-5b4e-7c1f-9a6e-0d8f3c2b1a00"),
+			vec!["GET", "PUT", "PATCH", "DELETE"]
+		);
+	}
+
+	#[test]
+	fn allowed_methods_of_a_static_path_ignore_dynamic_paths() {
+		assert_eq!(allowed_methods("/api/v1/things/count"), vec!["GET"]);
+		assert_eq!(allowed_methods("/api/v1/things/import"), vec!["POST"]);
+		assert_eq!(allowed_methods("/api/v1/things/by-slug/coffee"), vec!["GET"]);
+	}
+
+	#[test]
+	fn an_unknown_path_has_no_allowed_methods() {
+		assert!(allowed_methods("/api/v1/does-not-exist").is_empty());
+		assert!(allowed_methods("/not-the-api").is_empty());
+	}
+}
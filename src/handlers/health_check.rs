@@ -0,0 +1,82 @@
+//-- ./src/handlers/health_check.rs
+
+//! Server health check response handler, reporting on the services the server
+//! depends on
+//!
+//! # Health Check Handler
+//!
+//! This module is used for handling requests and responses to `/health_check`.
+//! Unlike `/ping`, the health check confirms the database is reachable.
+//!
+//! # References
+//!
+//! * [A guide to API health check](https://testfully.io/blog/api-health-check-monitoring/)
+
+use crate::{prelude::*, services::health_check};
+
+use actix_web::{web::Data, HttpResponse};
+use sqlx::PgPool;
+
+/// Health check response body
+#[derive(serde::Deserialize, serde::Serialize, Debug, PartialEq)]
+pub struct HealthCheck {
+	/// Overall server status, `ok` or `error`
+	pub status: String,
+	/// Database connectivity, `up` or `down`
+	pub database: String,
+}
+
+/// [GET] `/health_check` index endpoint handler
+///
+/// Run a lightweight query against the database pool, responding with `200 OK`
+/// if the database is up or `503 Service Unavailable` if it is down.
+///
+/// # Parameter
+///
+/// * `pool` - an Actix web data wrapper around a Postgres connection pool
+#[tracing::instrument(
+	name = "GET health check handler."
+	skip(pool)
+)]
+pub async fn index(pool: Data<PgPool>) -> Result<HttpResponse> {
+	match health_check::ping_database(&pool).await {
+		Ok(_) => Ok(HttpResponse::Ok().json(HealthCheck {
+			status: "ok".to_string(),
+			database: "up".to_string(),
+		})),
+		Err(error) => {
+			tracing::error!("Health check database query failed: {error}");
+			Ok(HttpResponse::ServiceUnavailable().json(HealthCheck {
+				status: "error".to_string(),
+				database: "down".to_string(),
+			}))
+		}
+	}
+}
+
+#[cfg(test)]
+pub mod tests {
+	// Bring file/module functions into unit test scope
+	use super::*;
+
+	// Override with more flexible error
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>;
+
+	#[sqlx::test]
+	async fn health_check_database_down(database: sqlx::Pool<sqlx::Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		// A closed pool can no longer reach the database
+		database.close().await;
+		let pool = Data::new(database);
+
+		//-- Execute Function (Act)
+		let response = index(pool).await?;
+
+		//-- Checks (Assertions)
+		// Check http status is service unavailable (503)
+		assert_eq!(503, response.status().as_u16());
+
+		Ok(())
+	}
+}
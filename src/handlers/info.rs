@@ -0,0 +1,48 @@
+//-- ./src/handlers/info.rs
+
+//! Server info response handler, reporting what is deployed
+//!
+//! # Info Handler
+//!
+//! This module is used for handling requests and responses to `/info`, so
+//! operators can confirm the build version, runtime environment and whether
+//! the database migrations are up to date.
+
+use crate::{configuration::Environment, prelude::*, services::health_check};
+
+use actix_web::{web::Data, HttpResponse};
+use sqlx::PgPool;
+
+/// Info response body
+#[derive(serde::Deserialize, serde::Serialize, Debug, PartialEq)]
+pub struct Info {
+	/// The crate version the server was built from
+	pub version: String,
+	/// Number of database migrations applied
+	pub migrations_applied: i64,
+	/// The runtime environment, i.e. `production`
+	pub environment: String,
+}
+
+/// [GET] `/info` index endpoint handler
+///
+/// Respond with the build version, the number of applied migrations and the
+/// runtime environment.
+///
+/// # Parameter
+///
+/// * `pool` - an Actix web data wrapper around a Postgres connection pool
+/// * `environment` - an Actix web data wrapper around the runtime environment
+#[tracing::instrument(
+	name = "GET info handler."
+	skip(pool)
+)]
+pub async fn index(pool: Data<PgPool>, environment: Data<Environment>) -> Result<HttpResponse> {
+	let migrations_applied = health_check::migrations_applied(&pool).await?;
+
+	Ok(HttpResponse::Ok().json(Info {
+		version: env!("CARGO_PKG_VERSION").to_string(),
+		migrations_applied,
+		environment: environment.as_str().to_string(),
+	}))
+}
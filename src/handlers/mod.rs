@@ -3,8 +3,14 @@
 //! Handlers are async functions that receives request-based arguments from routes.
 //! The Handlers then request data from a service and return a response to the route.
 //! 
+pub mod fallback;
+pub mod health_check;
+pub mod info;
+pub mod notes;
 pub mod ping;
+pub mod thing_events;
 pub mod things;
+pub mod verify;
 pub mod companies;
 pub mod errors;
 
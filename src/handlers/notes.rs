@@ -0,0 +1,163 @@
+//-- ./src/handlers/notes.rs
+
+//! Thing notes handler for receiving a request and providing a response
+//!
+//! Notes are a sub-resource of a Thing, nested under `/things/{thing_id}/notes`.
+//! ---
+
+use crate::{
+	domain::{Note, NoteBody},
+	prelude::*,
+	services::notes,
+};
+
+use actix_web::{web, HttpResponse};
+use actix_web::web::{Data, Form};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Expected Note form struct.
+#[derive(serde::Deserialize, Debug, PartialEq)]
+pub struct NoteFormData {
+	/// Body of the `Note` as a `String`
+	pub body: String,
+}
+
+/// Handle `[POST] api/v1/things/{thing_id}/notes` requests and respond with a note json
+///
+/// # Create Note
+///
+/// Add a note to the Thing with `{thing_id}`, or respond `404 Not Found` if
+/// there is no Thing with the id.
+///
+/// # Parameter
+///
+/// * `path` - the `{thing_id}` Uuid path parameter
+/// * `form` - an Actix web form struct
+/// * `pool` - an Actix web data wrapper around a Postgres connection pool
+/// ---
+#[tracing::instrument(
+	name = "POST thing note handler."
+	skip(path, form, pool),
+	fields(
+		thing_id = %path
+	)
+)]
+pub async fn create(
+	path: web::Path<Uuid>,
+	form: Form<NoteFormData>,
+	pool: Data<PgPool>,
+) -> Result<HttpResponse> {
+	let body = NoteBody::parse(&form.body)?;
+	let note = notes::insert(&Note::new(path.into_inner(), body), &pool).await?;
+
+	Ok(HttpResponse::Ok().json(note))
+}
+
+/// Handle `[GET] api/v1/things/{thing_id}/notes` requests and respond with a
+/// json array of notes, oldest first
+///
+/// # Parameter
+///
+/// * `path` - the `{thing_id}` Uuid path parameter
+/// * `pool` - an Actix web data wrapper around a Postgres connection pool
+/// ---
+#[tracing::instrument(
+	name = "GET index thing notes handler."
+	skip(path, pool),
+	fields(
+		thing_id = %path
+	)
+)]
+pub async fn read_index(
+	path: web::Path<Uuid>,
+	pool: Data<PgPool>,
+) -> Result<HttpResponse> {
+	let notes = notes::index_by_thing(&path, &pool).await?;
+
+	Ok(HttpResponse::Ok().json(notes))
+}
+
+/// Handle `[DELETE] api/v1/things/{thing_id}/notes/{note_id}` requests,
+/// responding with `204 No Content` on success or `404 Not Found` if the Thing
+/// or note does not exist.
+///
+/// # Parameter
+///
+/// * `path` - the `{thing_id}` and `{note_id}` Uuid path parameters
+/// * `pool` - an Actix web data wrapper around a Postgres connection pool
+/// ---
+#[tracing::instrument(
+	name = "DELETE thing note handler."
+	skip(path, pool)
+)]
+pub async fn delete_by_id(
+	path: web::Path<(Uuid, Uuid)>,
+	pool: Data<PgPool>,
+) -> Result<HttpResponse> {
+	let (thing_id, note_id) = path.into_inner();
+	notes::delete_by_id(&thing_id, &note_id, &pool).await?;
+
+	Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg(test)]
+pub mod tests {
+	// Bring file/module functions into unit test scope
+	use super::*;
+
+	// Override with more flexible error
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>;
+
+	use crate::services::things::{self, tests::create_random_test_thing};
+	use actix_web::body::MessageBody;
+	use fake::faker::lorem::en::Sentence;
+	use fake::Fake;
+
+	#[sqlx::test]
+	async fn create_and_list_notes(database: sqlx::Pool<sqlx::Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let thing = things::insert(&create_random_test_thing().await?, &database).await?;
+		let pool = Data::new(database.clone());
+		let mut bodies: Vec<String> = Vec::new();
+		for _count in 0..3 {
+			let body: String = Sentence(3..7).fake();
+			bodies.push(body.clone());
+			let response = create(
+				web::Path::from(thing.id),
+				Form(NoteFormData { body }),
+				pool.clone(),
+			)
+			.await?;
+			assert_eq!(200, response.status().as_u16());
+		}
+
+		//-- Execute Function (Act)
+		let response = read_index(web::Path::from(thing.id), pool).await?;
+
+		//-- Checks (Assertions)
+		assert_eq!(200, response.status().as_u16());
+		let body = response.into_body().try_into_bytes().unwrap();
+		let response_notes: Vec<Note> = serde_json::from_slice(&body)?;
+		let response_bodies: Vec<String> = response_notes
+			.into_iter()
+			.map(|note| note.body.into())
+			.collect();
+		assert_eq!(response_bodies, bodies);
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn list_notes_on_unknown_thing(database: sqlx::Pool<sqlx::Postgres>) -> Result<()> {
+		//-- Execute Function (Act)
+		let pool = Data::new(database.clone());
+		let error = read_index(web::Path::from(Uuid::now_v7()), pool).await.unwrap_err();
+
+		//-- Checks (Assertions)
+		assert_eq!(404, actix_web::ResponseError::status_code(&error).as_u16());
+
+		Ok(())
+	}
+}
@@ -0,0 +1,276 @@
+//-- ./src/handlers/thing_events.rs
+
+//! Thing event stream handlers, pushing Thing changes to clients as they happen
+//!
+//! # Thing Events
+//!
+//! Changes come from the `ThingChange` broadcast channel fed by
+//! `startup::forward_thing_changes`, so a frontend can live update without
+//! polling. They are sent as Server-Sent Events or WebSocket frames. Each
+//! subscriber has its own buffer, a subscriber that falls too far behind skips
+//! the changes it missed. Subscriptions end when the server starts shutting
+//! down, so they do not hold up a graceful shutdown.
+
+use crate::{prelude::*, services::things::ThingChange};
+
+use actix_web::web::{Bytes, Data, Payload};
+use actix_web::{http::header, HttpRequest, HttpResponse};
+use actix_ws::{CloseCode, CloseReason, Message, MessageStream, Session};
+use std::time::Duration;
+use tokio::sync::{broadcast, watch};
+
+/// Seconds between heartbeat comments on an idle event stream, so proxies do
+/// not close the connection
+pub const HEARTBEAT_SECONDS: u64 = 15;
+
+/// Set to `true` when the server starts shutting down, ending every Thing
+/// event subscription
+pub type ShuttingDown = watch::Receiver<bool>;
+
+/// Resolve once `shutting_down` is set, or never if its sender has gone
+async fn until_shutting_down(shutting_down: &mut ShuttingDown) {
+	if shutting_down.wait_for(|is_shutting_down| *is_shutting_down).await.is_err() {
+		futures::future::pending::<()>().await;
+	}
+}
+
+/// Media type of a Server-Sent Events response
+const EVENT_STREAM_CONTENT_TYPE: &str = "text/event-stream";
+
+/// Format a Thing change as a Server-Sent Event, named by the change action
+fn server_sent_event(change: &ThingChange) -> Result<Bytes> {
+	let data = serde_json::to_string(change)?;
+	let action = serde_json::to_value(change.action)?;
+	let action = action.as_str().unwrap_or("change");
+	Ok(Bytes::from(format!("event: {action}\ndata: {data}\n\n")))
+}
+
+/// Handle `[GET] api/v1/things/events` requests with a Server-Sent Events
+/// stream of Thing changes
+///
+/// # Thing Events
+///
+/// Each Thing insert, update and delete is sent as an event named by the action,
+/// with a json `{ "action": .., "id": .. }` data line. A `: heartbeat` comment
+/// is sent every `HEARTBEAT_SECONDS` the stream is idle. The stream starts with
+/// a `: connected` comment, once it is received later changes are sent. The
+/// stream ends when the server starts shutting down.
+///
+/// # Parameters
+///
+/// * `changes` - an Actix web data wrapper around the Thing change channel
+/// * `shutting_down` - an Actix web data wrapper around the shutdown signal
+/// ---
+#[tracing::instrument(
+	name = "GET thing events handler."
+	skip(changes, shutting_down)
+)]
+pub async fn events(
+	changes: Data<broadcast::Sender<ThingChange>>,
+	shutting_down: Data<ShuttingDown>,
+) -> Result<HttpResponse> {
+	let receiver = changes.subscribe();
+	let shutting_down = shutting_down.get_ref().clone();
+	let mut heartbeat = tokio::time::interval(Duration::from_secs(HEARTBEAT_SECONDS));
+	// The first tick completes straight away, it is the `connected` comment
+	heartbeat.tick().await;
+
+	let connected = futures::stream::once(async { Ok(Bytes::from_static(b": connected\n\n")) });
+	let state = (receiver, heartbeat, shutting_down);
+	let changes = futures::stream::unfold(state, |(mut receiver, mut heartbeat, mut shutting_down)| async move {
+		loop {
+			let event = tokio::select! {
+				change = receiver.recv() => match change {
+					Ok(change) => server_sent_event(&change),
+					Err(broadcast::error::RecvError::Lagged(missed)) => {
+						tracing::warn!("Thing event subscriber missed {missed} changes");
+						continue;
+					}
+					// The change channel has closed, so end the stream
+					Err(broadcast::error::RecvError::Closed) => return None,
+				},
+				_ = heartbeat.tick() => Ok(Bytes::from_static(b": heartbeat\n\n")),
+				// The server is shutting down, so end the stream
+				_ = until_shutting_down(&mut shutting_down) => return None,
+			};
+			return Some((event, (receiver, heartbeat, shutting_down)));
+		}
+	});
+
+	Ok(HttpResponse::Ok()
+		.content_type(EVENT_STREAM_CONTENT_TYPE)
+		.insert_header((header::CACHE_CONTROL, "no-cache"))
+		// Compressing would buffer events, so opt out of the compress middleware
+		.insert_header(header::ContentEncoding::Identity)
+		.streaming(futures::StreamExt::chain(connected, changes)))
+}
+
+/// Handle `[GET] api/v1/things/ws` requests by upgrading to a WebSocket that
+/// is sent Thing changes
+///
+/// # Thing Events
+///
+/// Each Thing insert, update and delete is sent as a json
+/// `{ "action": .., "id": .. }` text frame. Text frames from the client are
+/// echoed back, pings are answered with a pong and a close frame ends the
+/// subscription. The server closes the WebSocket when it starts shutting down.
+/// A request that is not a WebSocket upgrade returns a `400`.
+///
+/// # Parameters
+///
+/// * `request` - The HTTP upgrade request
+/// * `body` - The request payload, the client's WebSocket frames
+/// * `changes` - an Actix web data wrapper around the Thing change channel
+/// * `shutting_down` - an Actix web data wrapper around the shutdown signal
+/// ---
+#[tracing::instrument(
+	name = "GET thing events WebSocket handler."
+	skip(request, body, changes, shutting_down)
+)]
+pub async fn websocket(
+	request: HttpRequest,
+	body: Payload,
+	changes: Data<broadcast::Sender<ThingChange>>,
+	shutting_down: Data<ShuttingDown>,
+) -> actix_web::Result<HttpResponse> {
+	let (response, session, messages) = actix_ws::handle(&request, body)?;
+	actix_web::rt::spawn(forward_to_websocket(
+		session,
+		messages,
+		changes.subscribe(),
+		shutting_down.get_ref().clone(),
+	));
+
+	Ok(response)
+}
+
+/// Send each Thing change on `receiver` to the WebSocket `session`, answering
+/// the client's `messages`, until either side closes or `shutting_down` is set
+async fn forward_to_websocket(
+	mut session: Session,
+	mut messages: MessageStream,
+	mut receiver: broadcast::Receiver<ThingChange>,
+	mut shutting_down: ShuttingDown,
+) {
+	let reason = loop {
+		tokio::select! {
+			change = receiver.recv() => match change {
+				Ok(change) => {
+					let Ok(frame) = serde_json::to_string(&change) else { continue };
+					if session.text(frame).await.is_err() {
+						// The client has gone, so there is no one to close
+						return;
+					}
+				}
+				Err(broadcast::error::RecvError::Lagged(missed)) => {
+					tracing::warn!("Thing WebSocket subscriber missed {missed} changes");
+				}
+				// The change channel has closed, so end the subscription
+				Err(broadcast::error::RecvError::Closed) => {
+					break Some(CloseReason::from(CloseCode::Away));
+				}
+			},
+			message = messages.recv() => match message {
+				Some(Ok(Message::Text(text))) => {
+					if session.text(text).await.is_err() {
+						return;
+					}
+				}
+				Some(Ok(Message::Ping(bytes))) => {
+					if session.pong(&bytes).await.is_err() {
+						return;
+					}
+				}
+				Some(Ok(Message::Close(reason))) => break reason,
+				Some(Ok(_)) => {}
+				Some(Err(error)) => {
+					tracing::warn!("Thing WebSocket protocol error: {error}");
+					break Some(CloseReason::from(CloseCode::Protocol));
+				}
+				// The client disconnected without a close frame
+				None => return,
+			},
+			_ = until_shutting_down(&mut shutting_down) => {
+				break Some(CloseReason::from(CloseCode::Restart));
+			}
+		}
+	};
+
+	let _ = session.close(reason).await;
+}
+
+#[cfg(test)]
+pub mod tests {
+	// Bring file/module functions into unit test scope
+	use super::*;
+
+	use crate::services::things::ThingChangeAction;
+	use actix_web::body::MessageBody;
+	use uuid::Uuid;
+
+	#[actix_rt::test]
+	async fn events_streams_thing_changes() -> core::result::Result<(), Box<dyn std::error::Error>> {
+		//-- Setup and Fixtures (Arrange)
+		let (changes, _) = broadcast::channel(16);
+		let change = ThingChange { action: ThingChangeAction::Update, id: Uuid::now_v7() };
+
+		//-- Execute Function (Act)
+		let (_shutdown, shutting_down) = watch::channel(false);
+		let response = events(Data::new(changes.clone()), Data::new(shutting_down)).await?;
+		changes.send(change.clone())?;
+		let mut body = std::pin::pin!(response.into_body());
+		let connected = futures::future::poll_fn(|context| body.as_mut().poll_next(context)).await.unwrap()?;
+		let event = futures::future::poll_fn(|context| body.as_mut().poll_next(context)).await.unwrap()?;
+
+		//-- Checks (Assertions)
+		assert_eq!(connected, Bytes::from_static(b": connected\n\n"));
+		assert_eq!(
+			event,
+			Bytes::from(format!("event: update\ndata: {{\"action\":\"update\",\"id\":\"{}\"}}\n\n", change.id))
+		);
+
+		Ok(())
+	}
+
+	#[actix_rt::test]
+	async fn events_end_when_the_server_is_shutting_down() -> core::result::Result<(), Box<dyn std::error::Error>> {
+		//-- Setup and Fixtures (Arrange)
+		let (changes, _) = broadcast::channel(16);
+		let (shutdown, shutting_down) = watch::channel(false);
+		let response = events(Data::new(changes), Data::new(shutting_down)).await?;
+		let mut body = std::pin::pin!(response.into_body());
+		futures::future::poll_fn(|context| body.as_mut().poll_next(context)).await.unwrap()?;
+
+		//-- Execute Function (Act)
+		shutdown.send(true)?;
+		let end = tokio::time::timeout(
+			Duration::from_secs(5),
+			futures::future::poll_fn(|context| body.as_mut().poll_next(context)),
+		)
+		.await?;
+
+		//-- Checks (Assertions)
+		assert!(end.is_none());
+
+		Ok(())
+	}
+
+	#[actix_rt::test]
+	async fn websocket_without_an_upgrade_returns_400() -> core::result::Result<(), Box<dyn std::error::Error>> {
+		//-- Setup and Fixtures (Arrange)
+		let (changes, _) = broadcast::channel(16);
+		let (request, mut payload) = actix_web::test::TestRequest::get().to_http_parts();
+		let body = <Payload as actix_web::FromRequest>::from_request(&request, &mut payload).await?;
+
+		//-- Execute Function (Act)
+		let (_shutdown, shutting_down) = watch::channel(false);
+		let error = websocket(request, body, Data::new(changes), Data::new(shutting_down))
+			.await
+			.unwrap_err();
+
+		//-- Checks (Assertions)
+		assert_eq!(error.error_response().status().as_u16(), 400);
+
+		Ok(())
+	}
+}
@@ -20,13 +20,21 @@
 // #![allow(unused)] // For beginning only.
 
 use crate::{
-	domain::{ThingBuilder, ThingDescription, ThingName},
+	configuration::PaginationSettings,
+	domain::{ClientTimeZone, DateTimeRange, NewThing, Paginated, Thing, ThingBuilder, ThingDescription, ThingName, ThingSort, ThingStatus},
+	handlers,
 	prelude::*,
-	services::things
+	services::{self, things, thing_repository::ThingRepository},
+	services::idempotency::{Claim, IdempotencyStore, SavedResponse},
+	rate_limit,
 };
 
-use actix_web::{web, HttpResponse};
-use actix_web::web::{Data, Form};
+use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
+use actix_web::http::{header, StatusCode};
+use actix_web::web::{Bytes, Data, Form};
+use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
+use futures::StreamExt;
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -35,18 +43,222 @@ use uuid::Uuid;
 pub struct ThingFormData {
 	/// Name of the `Thing` as a `String`
 	pub name: String,
-	/// Description of the `Thing` as a `String`
-	pub description: String
+	/// Optional description of the `Thing`, an empty description is `None`
+	#[serde(default)]
+	pub description: Option<String>,
+	/// The `version` of the Thing an update is based on, ignored on create
+	#[serde(default)]
+	pub version: Option<i32>,
+}
+
+impl ThingFormData {
+	/// The description, or `None` if it is missing or only whitespace
+	fn description(&self) -> Option<&str> {
+		self.description
+			.as_deref()
+			.filter(|description| !description.trim().is_empty())
+	}
+
+	/// Parse the form fields into a `NewThing`, validating every field and
+	/// returning a `FormValidationError` listing each invalid field by name
+	fn validate(self) -> Result<NewThing> {
+		let mut errors = BTreeMap::new();
+		let description = match self.description().map(ThingDescription::parse).transpose() {
+			Ok(description) => description,
+			Err(error) => {
+				errors.insert("description".to_string(), error.to_string());
+				None
+			}
+		};
+		let name = match ThingName::parse(self.name) {
+			Ok(name) => Some(name),
+			Err(error) => {
+				errors.insert("name".to_string(), error.to_string());
+				None
+			}
+		};
+
+		match name {
+			Some(name) if errors.is_empty() => Ok(NewThing { name, description }),
+			_ => Err(Error::FormValidationError { errors }),
+		}
+	}
+}
+
+// Convert with `ThingFormData::validate`, so every field is validated
+impl TryFrom<ThingFormData> for NewThing {
+	type Error = Error;
+
+	fn try_from(form: ThingFormData) -> Result<Self> {
+		form.validate()
+	}
 }
 
 /// Optional Thing URL parameters.
 #[derive(serde::Deserialize, Debug)]
 pub struct ThingsParameters {
-	id: Option<Uuid>,
+	/// Only index Things with an id in this comma separated list of Uuids
+	ids: Option<String>,
     limit: Option<i64>,
-	offset: Option<i64>
+	offset: Option<i64>,
+	/// Only index Things with a name starting with this prefix
+	name: Option<String>,
+	/// Only index Things with a name or description matching these words,
+	/// ranked best match first
+	q: Option<String>,
+	/// Order of the indexed Things, defaults to newest first
+	sort: Option<ThingSort>,
+	/// Status of the indexed Things, defaults to active
+	status: Option<ThingStatus>,
+	/// Also index soft deleted Things, for admin views, defaults to false
+	include_deleted: Option<bool>,
+	/// Only index Things created at or after this timestamp
+	created_after: Option<DateTime<Utc>>,
+	/// Only index Things created at or before this timestamp
+	created_before: Option<DateTime<Utc>>,
+}
+
+/// Maximum number of names returned by the autocomplete endpoint
+const AUTOCOMPLETE_LIMIT: i64 = 10;
+
+/// Thing autocomplete URL parameters.
+#[derive(serde::Deserialize, Debug)]
+pub struct AutocompleteParameters {
+	/// Prefix the names must start with
+	q: String,
+	/// Optional number of names to return, capped at `AUTOCOMPLETE_LIMIT`
+	limit: Option<i64>,
+}
+
+/// Thing name availability URL parameters.
+#[derive(serde::Deserialize, Debug)]
+pub struct AvailableParameters {
+	/// Name to check, it must be a valid Thing name
+	name: String,
+}
+
+/// Thing batch lookup URL parameters.
+#[derive(serde::Deserialize, Debug)]
+pub struct ByIdsParameters {
+	/// Comma separated list of Uuids of the Things to read
+	ids: String,
+}
+
+/// Media type of a newline delimited JSON response, one record per line
+const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
+/// Does the request `Accept` header prefer a newline delimited JSON response,
+/// taking `q` weights into account so `application/x-ndjson;q=0` refuses it
+fn accepts_ndjson(request: &HttpRequest) -> bool {
+	use actix_web::http::header::Header;
+
+	header::Accept::parse(request)
+		.is_ok_and(|accept| accept.preference().essence_str() == NDJSON_CONTENT_TYPE)
+}
+
+/// A weak entity tag for `thing`, changing whenever the Thing is updated
+///
+/// Every update bumps the Thing `version`, so two updates within the same
+/// `updated_at` tick still have different tags.
+fn thing_etag(thing: &Thing) -> header::EntityTag {
+	header::EntityTag::new_weak(format!(
+		"{}-{}-{}",
+		thing.id.simple(),
+		thing.version,
+		thing.updated_at.timestamp_micros()
+	))
+}
+
+/// Does the request `If-None-Match` header match `etag`, so the client already
+/// has the current representation
+fn etag_matches(request: &HttpRequest, etag: &header::EntityTag) -> bool {
+	match request.get_header::<header::IfNoneMatch>() {
+		Some(header::IfNoneMatch::Any) => true,
+		Some(header::IfNoneMatch::Items(etags)) => etags.iter().any(|tag| tag.weak_eq(etag)),
+		None => false,
+	}
 }
 
+/// The `Last-Modified` date of `thing`, HTTP dates have second precision so
+/// `updated_at` is truncated to the second
+fn thing_last_modified(thing: &Thing) -> header::HttpDate {
+	let seconds = thing.updated_at.timestamp().max(0) as u64;
+	(std::time::UNIX_EPOCH + std::time::Duration::from_secs(seconds)).into()
+}
+
+/// Is the client copy of a Thing current, either the request `If-None-Match`
+/// matches `etag`, or without an `If-None-Match` the request
+/// `If-Modified-Since` is at or after `last_modified`
+fn not_modified(request: &HttpRequest, etag: &header::EntityTag, last_modified: header::HttpDate) -> bool {
+	// `If-None-Match` takes precedence over `If-Modified-Since`, RFC 7232 6
+	if request.headers().contains_key(header::IF_NONE_MATCH) {
+		return etag_matches(request, etag);
+	}
+
+	match request.get_header::<header::IfModifiedSince>() {
+		Some(header::IfModifiedSince(since)) => last_modified <= since,
+		None => false,
+	}
+}
+
+/// Serialise a Thing, or its localised json, as a line of newline delimited JSON
+fn ndjson_line(thing: impl serde::Serialize) -> Result<Bytes> {
+	let mut line = serde_json::to_vec(&thing)?;
+	line.push(b'\n');
+	Ok(Bytes::from(line))
+}
+
+/// Request header naming the IANA time zone, i.e. `Australia/Brisbane`, that
+/// read responses render timestamps in
+pub const TIME_ZONE_HEADER: &str = "Time-Zone";
+
+/// The time zone in the request `Time-Zone` header, UTC without one, or a
+/// `TimeZoneValidationError` (`400 Bad Request`) for an unknown time zone
+fn client_time_zone(request: &HttpRequest) -> Result<ClientTimeZone> {
+	match request.headers().get(TIME_ZONE_HEADER) {
+		Some(time_zone) => ClientTimeZone::parse(String::from_utf8_lossy(time_zone.as_bytes())),
+		None => Ok(ClientTimeZone::default()),
+	}
+}
+
+/// Resolve the optional `limit` and `offset` URL parameters to values, falling
+/// back to the configured default limit and the first record. A negative value
+/// is a `ParameterNegative` error, and a `limit` over the configured maximum is
+/// clamped to it.
+fn page_bounds(
+	limit: Option<i64>,
+	offset: Option<i64>,
+	pagination: &PaginationSettings,
+) -> Result<(i64, i64)> {
+	let limit = limit.unwrap_or(pagination.default_limit);
+	let offset = offset.unwrap_or(0);
+
+	if limit < 0 {
+		return Err(handlers::Error::ParameterNegative { name: "limit", value: limit }.into());
+	}
+	if offset < 0 {
+		return Err(handlers::Error::ParameterNegative { name: "offset", value: offset }.into());
+	}
+
+	Ok((limit.min(pagination.max_limit), offset))
+}
+
+/// Parse a comma separated list of Uuids, skipping empty entries, returning a
+/// `ThingIdInvalid` error with the offending value if an entry is not a Uuid.
+fn parse_ids(ids: &str) -> Result<Vec<Uuid>> {
+	ids.split(',')
+		.map(str::trim)
+		.filter(|id| !id.is_empty())
+		.map(|id| {
+			Uuid::parse_str(id)
+				.map_err(|_| handlers::Error::ThingIdInvalid { id: id.to_string() }.into())
+		})
+		.collect()
+}
+
+/// Header a client sends, with a unique value, to make a create safe to retry
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
 /// Handle `[POST] api/v1/thing` post requests and respond with a thing json
 /// 
 /// # Create Thing
@@ -54,133 +266,792 @@ pub struct ThingsParameters {
 /// Take post request to the endpoint, forward onto the database service and
 /// provide an HTTP Response
 /// 
+/// Every form field is validated, and a form with invalid fields is a
+/// `422 Unprocessable Entity` listing the error of each field in `errors`.
+/// 
+/// # Idempotency
+/// 
+/// A request with an `Idempotency-Key` header claims the key for the client,
+/// the authenticated API key or the peer IP address, before inserting the
+/// Thing. The response is saved against the key in the same transaction as
+/// the insert, and a retry with the same key gets the saved response rather
+/// than inserting the Thing again. A retry while the first request is still
+/// in progress is a `409 Conflict`. Error responses are not saved, so a failed
+/// request can be retried with the same key.
+/// 
 /// # Parameter
 /// 
+/// * `request` - the Actix web request, for the `Idempotency-Key` header
 /// * `form` - an Actix web form struct
-/// * `pool` - an Actix web data wrapper around a Postgres connection pool
+/// * `repository` - an Actix web data wrapper around the Thing repository
 /// ---
 #[tracing::instrument(
     name = "POST thing handler."
-    skip(form, pool),
+    skip(request, form, repository),
     fields(
         thing_name = %form.name,
-		thing_description = %form.description
+		thing_description = ?form.description
     )
 )]
 pub async fn create(
+	request: HttpRequest,
 	form: Form<ThingFormData>,
-	pool: Data<PgPool>,
+	repository: Data<dyn ThingRepository>,
 ) -> Result<HttpResponse> {
-	let name = ThingName::parse(&form.name)?;
-	let description = ThingDescription::parse(&form.description)?;
-
-	// TODO: is a type conversion better than a builder?
-	// let new_thing = match form.0.try_into() {
-    //     Ok(form) => form,
-    //     Err(_) => return HttpResponse::BadRequest().finish(),
-    // };
-
-	let new_thing = ThingBuilder::new(name)
-    	.description(description)
-    	.build()?;
+	let new_thing: NewThing = form.0.try_into()?;
+
+	let mut thing_builder = ThingBuilder::new(new_thing.name);
+	if let Some(description) = new_thing.description {
+		thing_builder.description(description);
+	}
+	let new_thing = thing_builder.build()?;
 	// println!("{new_thing:#?}");
 
-	let thing = things::insert(&new_thing, &pool).await?;
+	let idempotency_key = request
+		.headers()
+		.get(IDEMPOTENCY_KEY_HEADER)
+		.map(|key| String::from_utf8_lossy(key.as_bytes()).into_owned());
+	let (Some(store), Some(key)) = (request.app_data::<Data<IdempotencyStore>>(), idempotency_key) else {
+		let thing = repository.insert(&new_thing).await?;
+		return Ok(HttpResponse::Ok().json(thing));
+	};
+
+	let scope = rate_limit::client_key(&request);
+	match store.claim(&scope, &key).await? {
+		Claim::Claimed => {}
+		// Replay the saved response of an earlier request with the same key
+		Claim::Saved(saved) => return Ok(saved_response(saved)),
+		Claim::InProgress => return Err(services::Error::IdempotencyKeyInProgress.into()),
+	}
+
+	let response = insert_and_save(&new_thing, store, &scope, &key).await;
+	// Release the key so the request can be retried, returning the insert
+	// error even if the release fails
+	if response.is_err() {
+		if let Err(error) = store.release(&scope, &key).await {
+			tracing::error!("Failed to release the idempotency key: {error}");
+		}
+	}
+
+	Ok(saved_response(response?))
+}
+
+/// Insert `thing` and save its response for the claimed idempotency `key` in
+/// one transaction, so a saved response always has its Thing
+async fn insert_and_save(
+	thing: &Thing,
+	store: &IdempotencyStore,
+	scope: &str,
+	key: &str,
+) -> Result<SavedResponse> {
+	let mut transaction = store.begin().await?;
+	let thing = things::insert_in_transaction(thing, &mut transaction).await?;
 	// println!("{thing:#?}");
 
-	Ok(HttpResponse::Ok().json(thing))
+	let response = SavedResponse { status_code: 200, body: serde_json::to_string(&thing)? };
+	store.save(scope, key, &response, &mut transaction).await?;
+	transaction.commit().await?;
+
+	Ok(response)
+}
+
+/// Build the json response for a `SavedResponse`
+fn saved_response(saved: SavedResponse) -> HttpResponse {
+	let status = StatusCode::from_u16(saved.status_code).unwrap_or(StatusCode::OK);
+	HttpResponse::build(status)
+		.content_type(header::ContentType::json())
+		.body(saved.body)
+}
+
+/// Handle `[GET] api/v1/things/export` requests, responding with every Thing
+/// as newline delimited JSON
+///
+/// # Export Things
+///
+/// Stream every Thing that has not been soft deleted, oldest first, one json
+/// Thing per line. Rows are written to the response as they are read, so the
+/// export is never held in memory.
+///
+/// # Parameter
+///
+/// * `pool` - an Actix web data wrapper around a Postgres connection pool
+/// ---
+#[tracing::instrument(
+	name = "GET things export handler."
+	skip(pool)
+)]
+pub async fn export(pool: Data<PgPool>) -> Result<HttpResponse> {
+	let stream = things::export_stream(pool.get_ref().clone())
+		.map(|thing| thing.and_then(ndjson_line));
+
+	Ok(HttpResponse::Ok()
+		.content_type(NDJSON_CONTENT_TYPE)
+		.streaming(stream))
+}
+
+/// A Thing in an import that could not be validated
+#[derive(serde::Deserialize, serde::Serialize, Debug, PartialEq)]
+pub struct ImportError {
+	/// Position of the Thing in the imported array
+	pub index: usize,
+	/// The validation error message
+	pub error: String,
+	/// The validation error `code`
+	pub code: String,
+	/// The validation error message of each invalid field, by field name
+	#[serde(default)]
+	pub errors: BTreeMap<String, String>,
+}
+
+/// Thing import response body
+#[derive(serde::Deserialize, serde::Serialize, Debug, PartialEq)]
+pub struct ImportSummary {
+	/// Number of Things inserted
+	pub inserted: usize,
+	/// The Things that were not inserted because they are invalid
+	pub errors: Vec<ImportError>,
 }
 
-/// Handle `[GET] api/v1/thing` get requests and respond with a json collection
+/// Handle `[POST] api/v1/things/import` requests and respond with an import
+/// summary json
+///
+/// # Import Things
+///
+/// Validate each `{name, description}` json object in the array, inserting the
+/// valid Things in a single transaction. Invalid Things are skipped and listed
+/// in the summary `errors` by their array `index`. If a valid Thing can not be
+/// inserted, i.e. the name is taken, no Things are inserted.
+///
+/// # Parameter
+///
+/// * `json` - an Actix web json array of `ThingFormData`
+/// * `pool` - an Actix web data wrapper around a Postgres connection pool
+/// ---
+#[tracing::instrument(
+	name = "POST import things handler."
+	skip(json, pool),
+	fields(
+		thing_count = json.len()
+	)
+)]
+pub async fn import(
+	json: web::Json<Vec<ThingFormData>>,
+	pool: Data<PgPool>,
+) -> Result<HttpResponse> {
+	let mut new_things = Vec::with_capacity(json.len());
+	let mut errors = Vec::new();
+
+	for (index, form) in json.into_inner().into_iter().enumerate() {
+		let new_thing: NewThing = match form.try_into() {
+			Ok(new_thing) => new_thing,
+			Err(error) => {
+				let field_errors = match &error {
+					Error::FormValidationError { errors } => errors.clone(),
+					_ => BTreeMap::new(),
+				};
+				errors.push(ImportError {
+					index,
+					error: error.to_string(),
+					code: error.code().to_string(),
+					errors: field_errors,
+				});
+				continue;
+			}
+		};
+
+		let mut thing_builder = ThingBuilder::new(new_thing.name);
+		if let Some(description) = new_thing.description {
+			thing_builder.description(description);
+		}
+		new_things.push(thing_builder.build()?);
+	}
+
+	let inserted = things::insert_many(&new_things, &pool).await?.len();
+
+	Ok(HttpResponse::Ok().json(ImportSummary { inserted, errors }))
+}
+
+/// Handle `[GET] api/v1/thing` get requests and respond with a json page of Things
 /// 
 /// # Index Thing
 /// 
 /// Take get request to the endpoint, forward onto the database service and
-/// provide an HTTP Response
+/// provide an HTTP Response. The page includes the `total` number of Things so
+/// clients can build pagers.
 /// 
 /// # Parameter
 ///
-/// * `parameters` - A collection of optional URL parameters defined in `ThingsParameters`
+/// * `parameters` - A collection of optional URL parameters defined in `ThingsParameters`,
+///   when `name` is set only Things with a name starting with it are indexed and
+///   `sort` sets the order, defaulting to newest first. Archived Things are only
+///   indexed with `status=archived`. When `ids` is set only the Things in the
+///   comma separated list of Uuids are indexed, regardless of their status.
+///   When `q` is set, Things with the `status` and a name or description
+///   containing all of its words are indexed, best match first, regardless of
+///   `sort`. Soft deleted Things are only indexed with `include_deleted=true`
+///   and without `name`, `q` or `ids`, which also applies to the optional
+///   `created_after` and `created_before` RFC 3339 timestamp bounds
+/// * `request` - The request, an `Accept: application/x-ndjson` header streams
+///   the index without `name`, `q` or `ids` as one Thing per line, with no total.
+///   A `Time-Zone` header renders the timestamps in the named time zone
+/// * `pagination` - The configured default and maximum `limit`. Larger limits
+///   are clamped, the page `limit` is the effective limit, and a negative
+///   `limit` or `offset` is a `400 Bad Request`
 /// * `pool` - an Actix web data wrapper around a Postgres connection pool
 /// ---
 #[tracing::instrument(
     name = "GET index thing handler."
-    skip(parameters, pool),
+    skip(request, parameters, pagination, pool),
     fields(
-        query_limit = %parameters.limit.unwrap_or(10), // TODO: i64 does not have a display trait
-		query_offset = %parameters.offset.unwrap_or(0)
+        query_limit = ?parameters.limit,
+		query_offset = ?parameters.offset
     )
 )]
 pub async fn read_index(
+	request: HttpRequest,
 	parameters: web::Query<ThingsParameters>,
+	pagination: Data<PaginationSettings>,
+	pool: Data<PgPool>
+) -> Result<HttpResponse> {
+	let (limit, offset) = page_bounds(parameters.limit, parameters.offset, &pagination)?;
+	let time_zone = client_time_zone(&request)?;
+
+	let sort = parameters.sort.unwrap_or_default();
+	let status = parameters.status.unwrap_or_default();
+	let include_deleted = parameters.include_deleted.unwrap_or(false);
+	let created = DateTimeRange {
+		after: parameters.created_after,
+		before: parameters.created_before,
+	};
+
+	let is_filtered = parameters.ids.is_some() || parameters.q.is_some() || parameters.name.is_some();
+	if !is_filtered && accepts_ndjson(&request) {
+		let stream = things::index_stream(
+			limit,
+			offset,
+			sort,
+			status,
+			include_deleted,
+			created,
+			pool.get_ref().clone(),
+		)
+		.map(move |thing| thing.and_then(|thing| ndjson_line(time_zone.localise(&thing)?)));
+
+		return Ok(HttpResponse::Ok()
+			.content_type(NDJSON_CONTENT_TYPE)
+			.insert_header((header::VARY, "Accept, Time-Zone"))
+			.streaming(stream));
+	}
+
+	let (data, total) = match (&parameters.ids, &parameters.q, &parameters.name) {
+		(Some(ids), _, _) => {
+			let ids = parse_ids(ids)?;
+			(
+				things::index_by_ids(&ids, limit, offset, sort, &pool).await?,
+				things::count_by_ids(&ids, &pool).await?,
+			)
+		}
+		(None, Some(query), _) => (
+			things::full_text_search(query, limit, offset, status, &pool).await?,
+			things::count_full_text_search(query, status, &pool).await?,
+		),
+		(None, None, Some(prefix)) => (
+			things::search_by_name_prefix(prefix, limit, offset, sort, status, &pool).await?,
+			things::count_by_name_prefix(prefix, status, &pool).await?,
+		),
+		(None, None, None) => (
+			things::index_filtered(limit, offset, sort, status, include_deleted, created, &pool).await?,
+			things::count_filtered(status, include_deleted, created, &pool).await?,
+		),
+	};
+
+	Ok(HttpResponse::Ok()
+		.insert_header((header::VARY, "Accept, Time-Zone"))
+		.json(time_zone.localise(&Paginated { data, total, limit, offset })?))
+}
+
+/// Handle `[GET] api/v1/things/autocomplete?q=` requests and respond with a
+/// json array of names
+///
+/// # Autocomplete Thing names
+///
+/// A narrowly scoped, latency focused endpoint for type-ahead inputs, returning
+/// up to `AUTOCOMPLETE_LIMIT` Thing names starting with `q`.
+///
+/// # Parameter
+///
+/// * `parameters` - The `q` prefix and optional `limit` URL parameters
+/// * `pool` - an Actix web data wrapper around a Postgres connection pool
+/// ---
+#[tracing::instrument(
+	name = "GET autocomplete thing handler."
+	skip(parameters, pool),
+	fields(
+		query_prefix = %parameters.q,
+	)
+)]
+pub async fn autocomplete(
+	parameters: web::Query<AutocompleteParameters>,
 	pool: Data<PgPool>
 ) -> Result<HttpResponse> {
-	let limit = parameters.limit.unwrap_or(10); // TODO: Use application wide defaults
-	let offset = parameters.offset.unwrap_or(0); // TODO: Use application wide defaults
+	let limit = parameters
+		.limit
+		.unwrap_or(AUTOCOMPLETE_LIMIT)
+		.clamp(0, AUTOCOMPLETE_LIMIT);
+
+	let names = things::autocomplete(&parameters.q, limit, &pool).await?;
+
+	Ok(HttpResponse::Ok().json(names))
+}
+
+/// Thing count response body
+#[derive(serde::Deserialize, serde::Serialize, Debug, PartialEq)]
+pub struct ThingCount {
+	/// Number of Things, excluding soft deleted Things
+	pub count: i64,
+}
+
+/// Handle `[GET] api/v1/things/count` requests and respond with a count json
+///
+/// # Count Things
+///
+/// Return the number of Things that are not soft deleted, so totals can be
+/// shown without fetching any rows.
+///
+/// # Parameter
+///
+/// * `pool` - an Actix web data wrapper around a Postgres connection pool
+/// ---
+#[tracing::instrument(
+	name = "GET thing count handler."
+	skip(pool)
+)]
+pub async fn count(pool: Data<PgPool>) -> Result<HttpResponse> {
+	let count = things::count_all(&pool).await?;
 
-	let things = things::index(&limit, &offset, &pool).await?;
+	Ok(HttpResponse::Ok().json(ThingCount { count }))
+}
 
-	Ok(HttpResponse::Ok().json(things))
+/// Thing name availability response body
+#[derive(serde::Deserialize, serde::Serialize, Debug, PartialEq)]
+pub struct ThingNameAvailable {
+	/// False if a Thing, soft deleted or not, has the name ignoring case
+	pub available: bool,
 }
 
-/// Read a thing with `thing_id``
+/// Handle `[GET] api/v1/things/available?name=` requests and respond with an
+/// availability json
 ///
-/// Return a thing by ID
+/// # Thing Name Available
 ///
+/// Let a create form check a name before it is submitted. A name is taken if
+/// any Thing, including a soft deleted Thing, has it ignoring case, as a create
+/// with it would be a `409 Conflict`. An invalid name returns a `400` rather
+/// than an availability.
+///
+/// # Parameter
+///
+/// * `parameters` - The `name` URL parameter
+/// * `pool` - an Actix web data wrapper around a Postgres connection pool
+/// ---
 #[tracing::instrument(
-    name = "Read a things"
+	name = "GET thing name available handler."
 	skip(parameters, pool),
-    // fields(
-    //     thing_id = %info.thing_id,
-    // )
+	fields(
+		thing_name = %parameters.name,
+	)
+)]
+pub async fn available(
+	parameters: web::Query<AvailableParameters>,
+	pool: Data<PgPool>
+) -> Result<HttpResponse> {
+	let name = ThingName::parse(&parameters.name)?;
+
+	let available = !things::is_name_taken(&name, &pool).await?;
+
+	Ok(HttpResponse::Ok().json(ThingNameAvailable { available }))
+}
+
+/// Handle `[GET] api/v1/things/{thing_id}` requests and respond with a thing json
+///
+/// # Read Thing
+///
+/// Return the Thing with `{thing_id}`, or `404 Not Found` if there is no Thing
+/// with the id. A malformed id is a `400 Bad Request`.
+///
+/// The response has a weak `ETag` from the Thing id, version and `updated_at`,
+/// and a `Last-Modified` date from `updated_at`. It is a bodiless `304 Not
+/// Modified` when the request `If-None-Match` matches the `ETag`, or without an
+/// `If-None-Match` when the `If-Modified-Since` is not before `Last-Modified`.
+///
+/// # Parameter
+///
+/// * `request` - The request, for the conditional `If-None-Match` and
+///   `If-Modified-Since` headers, and the `Time-Zone` timestamps are rendered in
+/// * `path` - the `{thing_id}` Uuid path parameter
+/// * `repository` - an Actix web data wrapper around the Thing repository
+/// ---
+#[tracing::instrument(
+	name = "GET thing handler."
+	skip(request, path, repository),
+	fields(
+		thing_id = %path
+	)
 )]
 pub async fn read_by_id(
-	parameters: web::Query<ThingsParameters>,
+	request: HttpRequest,
+	path: web::Path<Uuid>,
+	repository: Data<dyn ThingRepository>
+) -> Result<HttpResponse> {
+	let time_zone = client_time_zone(&request)?;
+	let thing = repository.get_by_id(&path).await?;
+	let etag = thing_etag(&thing);
+	let last_modified = thing_last_modified(&thing);
+
+	if not_modified(&request, &etag, last_modified) {
+		return Ok(HttpResponse::NotModified()
+			.insert_header(header::ETag(etag))
+			.insert_header(header::LastModified(last_modified))
+			.finish());
+	}
+
+	Ok(HttpResponse::Ok()
+		.insert_header(header::ETag(etag))
+		.insert_header(header::LastModified(last_modified))
+		.insert_header((header::VARY, TIME_ZONE_HEADER))
+		.json(time_zone.localise(&thing)?))
+}
+
+/// Handle `[GET] api/v1/things/by-slug/{slug}` requests and respond with a thing json
+///
+/// # Read Thing by slug
+///
+/// Return the Thing with the URL friendly `{slug}` generated from its name, or
+/// `404 Not Found` if no Thing has the slug.
+///
+/// # Parameter
+///
+/// * `request` - The request, for the `Time-Zone` timestamps are rendered in
+/// * `path` - the `{slug}` path parameter
+/// * `pool` - an Actix web data wrapper around a Postgres connection pool
+/// ---
+#[tracing::instrument(
+	name = "GET thing by slug handler."
+	skip(request, path, pool),
+	fields(
+		thing_slug = %path
+	)
+)]
+pub async fn read_by_slug(
+	request: HttpRequest,
+	path: web::Path<String>,
 	pool: Data<PgPool>
 ) -> Result<HttpResponse> {
-	let id = parameters.id.ok_or(Error::ParameterMissing)?;
-	let thing = things::get_by_id(&id, &pool).await?;
+	let time_zone = client_time_zone(&request)?;
+	let thing = things::get_by_slug(&path, &pool).await?;
 
-	Ok(HttpResponse::Ok().json(thing))
+	Ok(HttpResponse::Ok()
+		.insert_header((header::VARY, TIME_ZONE_HEADER))
+		.json(time_zone.localise(&thing)?))
 }
 
-/// Update a Thing instance
+/// Handle `[GET] api/v1/things/by-ids?ids=` requests and respond with a json
+/// array of Things
+///
+/// # Read Things by ids
+///
+/// Return the Things with an id in the comma separated `ids` list, in the order
+/// requested, so a client holding a list of ids can make one request rather than
+/// one per Thing. Ids without a Thing are absent from the response.
+///
+/// # Parameter
+///
+/// * `request` - The request, for the `Time-Zone` timestamps are rendered in
+/// * `parameters` - The `ids` URL parameter
+/// * `pool` - an Actix web data wrapper around a Postgres connection pool
+/// ---
+#[tracing::instrument(
+	name = "GET things by ids handler."
+	skip(request, parameters, pool),
+	fields(
+		query_ids = %parameters.ids
+	)
+)]
+pub async fn read_by_ids(
+	request: HttpRequest,
+	parameters: web::Query<ByIdsParameters>,
+	pool: Data<PgPool>
+) -> Result<HttpResponse> {
+	let time_zone = client_time_zone(&request)?;
+	let ids = parse_ids(&parameters.ids)?;
+	let things = things::get_by_ids(&ids, &pool).await?;
+
+	Ok(HttpResponse::Ok()
+		.insert_header((header::VARY, TIME_ZONE_HEADER))
+		.json(time_zone.localise(&things)?))
+}
+
+/// Handle `[PUT] api/v1/things/{thing_id}` requests and respond with a thing json
+///
+/// # Update Thing
+///
+/// Update the Thing with `{thing_id}` from the form data and return it, or
+/// `404 Not Found` if there is no Thing with the id. A malformed id is a
+/// `400 Bad Request`.
+///
+/// The form `version` is the version of the Thing the update is based on, if
+/// the Thing has been updated since the response is a `409 Conflict`. Without a
+/// `version` the update is applied to the current version.
 ///
-/// Find a Thing by {thing_id}, update and return instance
+/// Every form field is validated as on create, and a form with invalid fields
+/// is a `422 Unprocessable Entity` listing the error of each field in `errors`.
 ///
-#[tracing::instrument(name = "Update things")]
+/// # Parameter
+///
+/// * `path` - the `{thing_id}` Uuid path parameter
+/// * `form` - the `ThingFormData` to update the Thing with
+/// * `repository` - an Actix web data wrapper around the Thing repository
+/// ---
+#[tracing::instrument(
+	name = "PUT thing handler."
+	skip(path, form, repository),
+	fields(
+		thing_id = %path
+	)
+)]
 pub async fn update_by_id(
-	parameters: web::Query<ThingsParameters>,
+	path: web::Path<Uuid>,
 	form: Form<ThingFormData>,
-	pool: Data<PgPool>
+	repository: Data<dyn ThingRepository>
 ) -> Result<HttpResponse>  {
-	let uuid = parameters.id.ok_or(Error::ParameterMissing)?;
-	let name = ThingName::parse(&form.name)?;
-	let description = ThingDescription::parse(&form.description)?;
+	let id = path.into_inner();
+	let version = match form.version {
+		Some(version) => version,
+		None => repository.get_by_id(&id).await?.version,
+	};
+	let new_thing: NewThing = form.0.try_into()?;
 
-	let thing = ThingBuilder::new(name)
-		.id(uuid)
-		.description(description)
-		.build()?;
+	let mut thing_builder = ThingBuilder::new(new_thing.name);
+	thing_builder.id(id).version(version);
+	if let Some(description) = new_thing.description {
+		thing_builder.description(description);
+	}
+	let thing = thing_builder.build()?;
 
-	let updated_thing = things::update(&thing, &pool).await?;
+	let updated_thing = repository.update(&thing).await?;
 
 	Ok(HttpResponse::Ok().json(updated_thing))
 }
 
-/// Delete a Thing by thing_id
+/// Content type of a JSON merge patch, RFC 7396
+pub const MERGE_PATCH_CONTENT_TYPE: &str = "application/merge-patch+json";
+
+/// Handle `[PATCH] api/v1/things/{thing_id}` JSON merge patch requests and
+/// respond with a thing json
+///
+/// # Merge Patch Thing
+///
+/// Apply the `application/merge-patch+json` body to the Thing with
+/// `{thing_id}`, where a field present in the patch is set, a `null` field is
+/// cleared and an omitted field is left unchanged. Unlike the form `PUT`, the
+/// description is only cleared by an explicit `null`.
+///
+/// * `name` - a new name, it can not be cleared
+/// * `description` - a new description, or `null` to clear it
+/// * `version` - the version the patch is based on, a stale version is a
+///   `409 Conflict`
+///
+/// Another content type is a `415 Unsupported Media Type`, a body that is not
+/// a JSON object a `400 Bad Request` and invalid fields a
+/// `422 Unprocessable Entity` listing each invalid field.
+///
+/// # Parameter
+///
+/// * `request` - the Actix web request, for the `Content-Type` header
+/// * `path` - the `{thing_id}` Uuid path parameter
+/// * `body` - the merge patch json
+/// * `repository` - an Actix web data wrapper around the Thing repository
+/// ---
+#[tracing::instrument(
+	name = "PATCH thing handler."
+	skip(request, path, body, repository),
+	fields(
+		thing_id = %path
+	)
+)]
+pub async fn merge_patch_by_id(
+	request: HttpRequest,
+	path: web::Path<Uuid>,
+	body: Bytes,
+	repository: Data<dyn ThingRepository>,
+) -> Result<HttpResponse> {
+	let content_type = request.content_type();
+	if content_type != MERGE_PATCH_CONTENT_TYPE {
+		return Err(handlers::Error::UnsupportedMediaType {
+			content_type: content_type.to_string(),
+			expected: MERGE_PATCH_CONTENT_TYPE,
+		}
+		.into());
+	}
+	let patch: serde_json::Value = serde_json::from_slice(&body)
+		.map_err(|error| handlers::Error::MergePatchInvalid { reason: error.to_string() })?;
+
+	let thing = repository.get_by_id(&path.into_inner()).await?;
+	let thing = apply_merge_patch(thing, &patch)?;
+	let updated_thing = repository.update(&thing).await?;
+
+	Ok(HttpResponse::Ok().json(updated_thing))
+}
+
+/// Apply the merge `patch` to `thing`, returning a `FormValidationError`
+/// listing each invalid field
+fn apply_merge_patch(mut thing: Thing, patch: &serde_json::Value) -> Result<Thing> {
+	let patch = patch.as_object().ok_or_else(|| handlers::Error::MergePatchInvalid {
+		reason: format!("got {patch}"),
+	})?;
+	let mut errors = BTreeMap::new();
+
+	match patch.get("name") {
+		None => {}
+		Some(serde_json::Value::String(name)) => match ThingName::parse(name.as_str()) {
+			Ok(name) => thing.name = name,
+			Err(error) => {
+				errors.insert("name".to_string(), error.to_string());
+			}
+		},
+		Some(_) => {
+			errors.insert("name".to_string(), "name must be a string, it can not be cleared.".to_string());
+		}
+	}
+
+	match patch.get("description") {
+		None => {}
+		Some(serde_json::Value::Null) => thing.description = None,
+		Some(serde_json::Value::String(description)) => match ThingDescription::parse(description.as_str()) {
+			Ok(description) => thing.description = Some(description),
+			Err(error) => {
+				errors.insert("description".to_string(), error.to_string());
+			}
+		},
+		Some(_) => {
+			errors.insert("description".to_string(), "description must be a string or null.".to_string());
+		}
+	}
+
+	match patch.get("version") {
+		None | Some(serde_json::Value::Null) => {}
+		Some(version) => match version.as_i64().and_then(|version| i32::try_from(version).ok()) {
+			Some(version) => thing.version = version,
+			None => {
+				errors.insert("version".to_string(), "version must be an integer.".to_string());
+			}
+		},
+	}
+
+	if !errors.is_empty() {
+		return Err(Error::FormValidationError { errors });
+	}
+
+	Ok(thing)
+}
+
+/// Handle `[POST] api/v1/things/{thing_id}/restore` requests
+///
+/// # Restore Thing
+///
+/// Restore the soft deleted Thing with `{thing_id}`, responding with the
+/// restored thing json or a `ThingUnknownId` error (`404 Not Found`) if there
+/// is no soft deleted Thing with the id.
+///
+/// # Parameter
+///
+/// * `path` - the `{thing_id}` Uuid path parameter
+/// * `pool` - an Actix web data wrapper around a Postgres connection pool
+/// ---
+#[tracing::instrument(
+	name = "POST restore thing handler."
+	skip(path, pool),
+	fields(
+		thing_id = %path
+	)
+)]
+pub async fn restore_by_id(
+	path: web::Path<Uuid>,
+	pool: Data<PgPool>
+) -> Result<HttpResponse> {
+	let thing = things::restore_by_id(&path, &pool).await?;
+
+	Ok(HttpResponse::Ok().json(thing))
+}
+
+/// Handle `[DELETE] api/v1/things/{thing_id}` requests
 ///
-/// Find a Thing by {thing_id}, update and return confirmation
+/// # Delete Thing
 ///
-#[tracing::instrument(name = "Delete things")]
+/// Delete the Thing with `{thing_id}`, responding with `204 No Content` on
+/// success or a `ThingUnknownId` error (`404 Not Found`) if no Thing was deleted.
+///
+/// # Parameter
+///
+/// * `path` - the `{thing_id}` Uuid path parameter
+/// * `repository` - an Actix web data wrapper around the Thing repository
+/// ---
+#[tracing::instrument(
+	name = "DELETE thing handler."
+	skip(path, repository),
+	fields(
+		thing_id = %path
+	)
+)]
 pub async fn delete_by_id(
-	parameters: web::Query<ThingsParameters>,
+	path: web::Path<Uuid>,
+	repository: Data<dyn ThingRepository>
+) -> Result<HttpResponse> {
+	let id = path.into_inner();
+	let number_of_things_deleted = repository.delete_by_id(&id).await?;
+
+	if number_of_things_deleted == 0 {
+		return Err(services::Error::ThingUnknownId.into());
+	}
+
+	Ok(HttpResponse::NoContent().finish())
+}
+
+/// Bulk delete response body
+#[derive(serde::Deserialize, serde::Serialize, Debug, PartialEq)]
+pub struct ThingsDeleted {
+	/// Number of Things deleted
+	pub deleted: u64,
+}
+
+/// Handle `[DELETE] api/v1/things` requests and respond with a deleted count
+/// json
+///
+/// # Delete Things
+///
+/// Delete the Things with any of the Uuids in the json array, in a single
+/// transaction. Uuids without a Thing are ignored, so the count can be less
+/// than the number of Uuids.
+///
+/// # Parameter
+///
+/// * `json` - A json array of the Thing Uuids to delete
+/// * `pool` - an Actix web data wrapper around a Postgres connection pool
+/// ---
+#[tracing::instrument(
+	name = "DELETE things handler."
+	skip(json, pool),
+	fields(
+		thing_count = json.len(),
+	)
+)]
+pub async fn delete_by_ids(
+	json: web::Json<Vec<Uuid>>,
 	pool: Data<PgPool>
 ) -> Result<HttpResponse> {
-	let id = parameters.id.ok_or(Error::ParameterMissing)?;
-	let number_of_things_deleted = things::delete_by_id(&id, &pool).await?;
+	let deleted = things::delete_by_ids(&json, &pool).await?;
 
-	Ok(HttpResponse::Ok().json(number_of_things_deleted))
+	Ok(HttpResponse::Ok().json(ThingsDeleted { deleted }))
 }
 
 #[cfg(test)]
@@ -199,6 +1070,321 @@ pub mod tests {
 	use actix_web::web;
 	use actix_web::body::MessageBody;
 	use crate::services::things::insert;
+	use crate::services::thing_repository::{InMemoryThingRepository, PgThingRepository};
+	use std::sync::Arc;
+
+	// Wrap the test database in a Thing repository, as the application does
+	fn thing_repository(database: &PgPool) -> Data<dyn ThingRepository> {
+		let repository: Arc<dyn ThingRepository> = Arc::new(PgThingRepository::new(database.clone()));
+		Data::from(repository)
+	}
+
+	#[actix_rt::test]
+	async fn read_thing_by_id_renders_timestamps_in_the_client_time_zone() -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let created_at = chrono::TimeZone::with_ymd_and_hms(&Utc, 2024, 1, 1, 0, 0, 0).unwrap();
+		let test_thing = ThingBuilder::new(ThingName::parse("Time zoned")?)
+			.created_at(created_at)
+			.updated_at(created_at)
+			.build()?;
+		let repository: Arc<dyn ThingRepository> =
+			Arc::new(InMemoryThingRepository::with_things(vec![test_thing.clone()]));
+		let request = actix_web::test::TestRequest::default()
+			.insert_header((TIME_ZONE_HEADER, "Australia/Brisbane"))
+			.to_http_request();
+		let unknown_request = actix_web::test::TestRequest::default()
+			.insert_header((TIME_ZONE_HEADER, "Mars/Olympus_Mons"))
+			.to_http_request();
+
+		//-- Execute Function (Act)
+		let response = read_by_id(request, web::Path::from(test_thing.id), Data::from(repository.clone())).await?;
+		let error = read_by_id(unknown_request, web::Path::from(test_thing.id), Data::from(repository))
+			.await
+			.unwrap_err();
+
+		//-- Checks (Assertions)
+		assert_eq!(response.headers().get(header::VARY).unwrap(), TIME_ZONE_HEADER);
+		let body = response.into_body().try_into_bytes().unwrap();
+		let response_body: serde_json::Value = serde_json::from_slice(&body)?;
+		assert_eq!(response_body["created_at"], "2024-01-01T10:00:00.000+10:00");
+		assert_eq!(response_body["updated_at"], "2024-01-01T10:00:00.000+10:00");
+		assert_eq!(response_body["deleted_at"], serde_json::Value::Null);
+		assert_eq!(400, actix_web::ResponseError::status_code(&error).as_u16());
+
+		Ok(())
+	}
+
+	#[actix_rt::test]
+	async fn read_thing_by_id_is_not_modified_for_a_matching_etag() -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let test_thing = create_random_test_thing().await?;
+		let repository: Arc<dyn ThingRepository> =
+			Arc::new(InMemoryThingRepository::with_things(vec![test_thing.clone()]));
+		let etag = thing_etag(&test_thing);
+		let matching_request = actix_web::test::TestRequest::default()
+			.insert_header((header::IF_NONE_MATCH, etag.to_string()))
+			.to_http_request();
+		let other_request = actix_web::test::TestRequest::default()
+			.insert_header((header::IF_NONE_MATCH, "W/\"other\""))
+			.to_http_request();
+
+		//-- Execute Function (Act)
+		let not_modified = read_by_id(matching_request, web::Path::from(test_thing.id), Data::from(repository.clone())).await?;
+		let modified = read_by_id(other_request, web::Path::from(test_thing.id), Data::from(repository)).await?;
+
+		//-- Checks (Assertions)
+		assert_eq!(304, not_modified.status().as_u16());
+		assert_eq!(not_modified.headers().get(header::ETAG).unwrap(), &etag.to_string());
+		assert!(not_modified.into_body().try_into_bytes().unwrap().is_empty());
+		assert_eq!(200, modified.status().as_u16());
+		assert_eq!(modified.headers().get(header::ETAG).unwrap(), &etag.to_string());
+
+		Ok(())
+	}
+
+	#[actix_rt::test]
+	async fn thing_etag_changes_for_an_update_at_the_same_instant() -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let test_thing = create_random_test_thing().await?;
+		let mut updated_thing = test_thing.clone();
+		updated_thing.version += 1;
+
+		//-- Execute Function (Act)
+		let etag = thing_etag(&test_thing);
+		let updated_etag = thing_etag(&updated_thing);
+
+		//-- Checks (Assertions)
+		assert_eq!(test_thing.updated_at, updated_thing.updated_at);
+		assert!(!etag.weak_eq(&updated_etag));
+
+		Ok(())
+	}
+
+	#[actix_rt::test]
+	async fn read_thing_by_id_is_not_modified_since_last_modified() -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		// Sub-second precision, which HTTP dates do not have
+		let updated_at = chrono::TimeZone::with_ymd_and_hms(&Utc, 2024, 1, 1, 12, 0, 0).unwrap()
+			+ chrono::Duration::milliseconds(750);
+		let mut test_thing = create_random_test_thing().await?;
+		test_thing.updated_at = updated_at;
+		let repository: Arc<dyn ThingRepository> =
+			Arc::new(InMemoryThingRepository::with_things(vec![test_thing.clone()]));
+		let request_since = |since: &str| {
+			actix_web::test::TestRequest::default()
+				.insert_header((header::IF_MODIFIED_SINCE, since))
+				.to_http_request()
+		};
+
+		//-- Execute Function (Act)
+		let same_second = read_by_id(
+			request_since("Mon, 01 Jan 2024 12:00:00 GMT"),
+			web::Path::from(test_thing.id),
+			Data::from(repository.clone()),
+		)
+		.await?;
+		let second_before = read_by_id(
+			request_since("Mon, 01 Jan 2024 11:59:59 GMT"),
+			web::Path::from(test_thing.id),
+			Data::from(repository.clone()),
+		)
+		.await?;
+		// A stale `If-None-Match` takes precedence over a current `If-Modified-Since`
+		let stale_etag = read_by_id(
+			actix_web::test::TestRequest::default()
+				.insert_header((header::IF_NONE_MATCH, "W/\"other\""))
+				.insert_header((header::IF_MODIFIED_SINCE, "Mon, 01 Jan 2024 12:00:00 GMT"))
+				.to_http_request(),
+			web::Path::from(test_thing.id),
+			Data::from(repository),
+		)
+		.await?;
+
+		//-- Checks (Assertions)
+		assert_eq!(304, same_second.status().as_u16());
+		assert_eq!(
+			same_second.headers().get(header::LAST_MODIFIED).unwrap(),
+			"Mon, 01 Jan 2024 12:00:00 GMT"
+		);
+		assert_eq!(200, second_before.status().as_u16());
+		assert_eq!(200, stale_etag.status().as_u16());
+
+		Ok(())
+	}
+
+	#[actix_rt::test]
+	async fn update_thing_in_memory() -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		// No database, the handlers only need a Thing repository
+		let test_thing = create_random_test_thing().await?;
+		let in_memory = Arc::new(InMemoryThingRepository::with_things(vec![test_thing.clone()]));
+		let repository: Arc<dyn ThingRepository> = in_memory.clone();
+		let updated_name = format!("{} updated", test_thing.name.as_ref());
+		let form = ThingFormData { name: updated_name.clone(), description: None, version: Some(test_thing.version) };
+		let stale_form = ThingFormData { name: updated_name.clone(), description: None, version: Some(test_thing.version) };
+
+		//-- Execute Function (Act)
+		let response = update_by_id(web::Path::from(test_thing.id), Form(form), Data::from(repository.clone())).await?;
+		let stale_error = update_by_id(web::Path::from(test_thing.id), Form(stale_form), Data::from(repository.clone()))
+			.await
+			.unwrap_err();
+		let unknown_error = read_by_id(actix_web::test::TestRequest::default().to_http_request(), web::Path::from(Uuid::now_v7()), Data::from(repository)).await.unwrap_err();
+
+		//-- Checks (Assertions)
+		assert_eq!(200, response.status().as_u16());
+		let body = response.into_body().try_into_bytes().unwrap();
+		let response_thing: Thing = serde_json::from_slice(&body)?;
+		assert_eq!(response_thing.name.as_ref(), &updated_name);
+		assert_eq!(response_thing.version, test_thing.version + 1);
+		assert_eq!(in_memory.things(), vec![response_thing]);
+		assert_eq!(409, actix_web::ResponseError::status_code(&stale_error).as_u16());
+		assert_eq!(404, actix_web::ResponseError::status_code(&unknown_error).as_u16());
+
+		Ok(())
+	}
+
+	#[actix_rt::test]
+	async fn update_thing_with_invalid_fields_lists_every_field() -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let test_thing = create_random_test_thing().await?;
+		let repository: Arc<dyn ThingRepository> =
+			Arc::new(InMemoryThingRepository::with_things(vec![test_thing.clone()]));
+		let form = ThingFormData {
+			name: "{invalid}".to_string(),
+			description: Some("{invalid}".to_string()),
+			version: Some(test_thing.version),
+		};
+
+		//-- Execute Function (Act)
+		let error = update_by_id(web::Path::from(test_thing.id), Form(form), Data::from(repository))
+			.await
+			.unwrap_err();
+
+		//-- Checks (Assertions)
+		assert_eq!(422, actix_web::ResponseError::status_code(&error).as_u16());
+		let crate::error::Error::FormValidationError { errors } = error else {
+			panic!("Expected a FormValidationError, got {error:?}");
+		};
+		assert_eq!(errors.keys().collect::<Vec<_>>(), vec!["description", "name"]);
+
+		Ok(())
+	}
+
+	/// Send the merge `patch` for `thing` to `merge_patch_by_id` over an in
+	/// memory repository, returning the response or error
+	async fn merge_patch_in_memory(thing: &Thing, patch: serde_json::Value) -> Result<actix_web::Result<Thing>> {
+		let repository: Arc<dyn ThingRepository> = Arc::new(InMemoryThingRepository::with_things(vec![thing.clone()]));
+		let request = actix_web::test::TestRequest::patch()
+			.insert_header((header::CONTENT_TYPE, MERGE_PATCH_CONTENT_TYPE))
+			.to_http_request();
+
+		let response = merge_patch_by_id(
+			request,
+			web::Path::from(thing.id),
+			Bytes::from(patch.to_string()),
+			Data::from(repository),
+		)
+		.await;
+
+		Ok(match response {
+			Ok(response) => Ok(serde_json::from_slice(&response.into_body().try_into_bytes().unwrap())?),
+			Err(error) => Err(error.into()),
+		})
+	}
+
+	#[actix_rt::test]
+	async fn merge_patch_null_clears_the_description() -> Result<()> {
+		let test_thing = create_random_test_thing().await?;
+
+		let thing = merge_patch_in_memory(&test_thing, serde_json::json!({ "description": null })).await??;
+
+		assert_eq!(thing.description, None);
+		assert_eq!(thing.name, test_thing.name);
+		assert_eq!(thing.version, test_thing.version + 1);
+
+		Ok(())
+	}
+
+	#[actix_rt::test]
+	async fn merge_patch_omitted_fields_are_unchanged() -> Result<()> {
+		let test_thing = create_random_test_thing().await?;
+		let updated_name = format!("{} patched", test_thing.name.as_ref());
+
+		let thing = merge_patch_in_memory(&test_thing, serde_json::json!({ "name": updated_name })).await??;
+
+		assert_eq!(thing.name.as_ref(), &updated_name);
+		assert!(thing.description.is_some());
+		assert_eq!(thing.description, test_thing.description);
+
+		Ok(())
+	}
+
+	#[actix_rt::test]
+	async fn merge_patch_rejects_invalid_patches() -> Result<()> {
+		let test_thing = create_random_test_thing().await?;
+		let status = |error: actix_web::Error| error.as_response_error().status_code().as_u16();
+
+		let cleared_name = merge_patch_in_memory(&test_thing, serde_json::json!({ "name": null })).await?;
+		let not_an_object = merge_patch_in_memory(&test_thing, serde_json::json!(["name"])).await?;
+		let stale = merge_patch_in_memory(&test_thing, serde_json::json!({ "version": test_thing.version + 1 })).await?;
+
+		assert_eq!(status(cleared_name.unwrap_err()), 422);
+		assert_eq!(status(not_an_object.unwrap_err()), 400);
+		assert_eq!(status(stale.unwrap_err()), 409);
+
+		Ok(())
+	}
+
+	#[actix_rt::test]
+	async fn merge_patch_requires_the_merge_patch_content_type() -> Result<()> {
+		let test_thing = create_random_test_thing().await?;
+		let repository: Arc<dyn ThingRepository> = Arc::new(InMemoryThingRepository::with_things(vec![test_thing.clone()]));
+		let request = actix_web::test::TestRequest::patch()
+			.insert_header((header::CONTENT_TYPE, "application/json"))
+			.to_http_request();
+
+		let error = merge_patch_by_id(request, web::Path::from(test_thing.id), Bytes::from("{}"), Data::from(repository))
+			.await
+			.unwrap_err();
+
+		assert_eq!(415, actix_web::ResponseError::status_code(&error).as_u16());
+		assert_eq!(error.code(), "UnsupportedMediaType");
+
+		Ok(())
+	}
+
+	#[test]
+	fn thing_form_data_converts_into_a_new_thing() -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let name: String = Word().fake();
+		let description: String = Sentence(3..7).fake();
+		let form = ThingFormData { name: name.clone(), description: Some(description.clone()), version: None };
+
+		//-- Execute Function (Act)
+		let new_thing = NewThing::try_from(form)?;
+
+		//-- Checks (Assertions)
+		assert_eq!(new_thing.name, ThingName::parse(name)?);
+		assert_eq!(new_thing.description, Some(ThingDescription::parse(description)?));
+
+		Ok(())
+	}
+
+	#[test]
+	fn thing_form_data_with_invalid_fields_does_not_convert() {
+		//-- Setup and Fixtures (Arrange)
+		let form = ThingFormData { name: "{invalid}".to_string(), description: Some("{invalid}".to_string()), version: None };
+
+		//-- Execute Function (Act)
+		let result = NewThing::try_from(form);
+
+		//-- Checks (Assertions)
+		// Every invalid field is reported, not only the first
+		let Err(crate::error::Error::FormValidationError { errors }) = result else {
+			panic!("Expected a FormValidationError, got {result:?}");
+		};
+		assert_eq!(errors.keys().collect::<Vec<_>>(), vec!["description", "name"]);
+	}
 
 	#[sqlx::test]
 	async fn create_a_thing(database: sqlx::Pool<sqlx::Postgres>) -> Result<()> {
@@ -208,12 +1394,12 @@ pub mod tests {
 		let description: String = Sentence(3..7).fake();
 		let query_description = description.clone(); // TODO: This clone is ugly
 		let form = Form(
-			ThingFormData { name, description }
+			ThingFormData { name, description: Some(description), version: None }
 		);
-		let pool = Data::new(database.clone());
+		let repository = thing_repository(&database);
 
 		//-- Execute Function (Act)
-		let response = create(form, pool).await?;
+		let response = create(actix_web::test::TestRequest::default().to_http_request(), form, repository).await?;
 		// println!("{response:#?}");
 
 		//-- Checks (Assertions)
@@ -234,16 +1420,131 @@ pub mod tests {
 		.await?;
 		// println!("{database_record:#?}");
 
-		assert_eq!(query_name, database_record.name);
-		assert_eq!(query_description, database_record.description.unwrap());
+		assert_eq!(query_name, database_record.name);
+		assert_eq!(query_description, database_record.description.unwrap());
+
+		// Check return body json equals query name and description
+		let body = response.into_body().try_into_bytes().unwrap();
+		// pin!(body);
+		let response_thing: Thing = serde_json::from_slice(&body).unwrap();
+
+		assert_eq!(&query_name, response_thing.name.as_ref());
+		assert_eq!(&query_description, response_thing.description.unwrap().as_ref());
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn import_things_reports_invalid_indices(database: sqlx::Pool<sqlx::Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let word: String = Word().fake();
+		let json = web::Json(vec![
+			ThingFormData { name: format!("{word} one"), description: Some(Sentence(3..7).fake()), version: None },
+			ThingFormData { name: "{invalid}".to_string(), description: None, version: None },
+			ThingFormData { name: format!("{word} two"), description: None, version: None },
+			ThingFormData { name: "".to_string(), description: None, version: None },
+		]);
+		let pool = Data::new(database.clone());
+
+		//-- Execute Function (Act)
+		let response = import(json, pool).await?;
+
+		//-- Checks (Assertions)
+		assert_eq!(200, response.status().as_u16());
+		let body = response.into_body().try_into_bytes().unwrap();
+		let summary: ImportSummary = serde_json::from_slice(&body)?;
+		assert_eq!(summary.inserted, 2);
+		let error_indices: Vec<usize> = summary.errors.iter().map(|error| error.index).collect();
+		assert_eq!(error_indices, vec![1, 3]);
+		assert!(summary.errors.iter().all(|error| error.code == "FormValidationError"));
+		assert!(summary.errors.iter().all(|error| error.errors.contains_key("name")));
+		// Only the valid Things are in the database
+		assert_eq!(things::count_all(&database).await?, 2);
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn create_a_thing_without_a_description(database: sqlx::Pool<sqlx::Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let name: String = Word().fake();
+		// A missing and an empty description are both stored as `NULL`
+		let form = Form(ThingFormData { name: name.clone(), description: None, version: None });
+		let empty_form = Form(ThingFormData { name: format!("{name} two"), description: Some(" ".to_string()), version: None });
+		let repository = thing_repository(&database);
+
+		//-- Execute Function (Act)
+		let response = create(actix_web::test::TestRequest::default().to_http_request(), form, repository.clone()).await?;
+		let empty_response = create(actix_web::test::TestRequest::default().to_http_request(), empty_form, repository).await?;
+
+		//-- Checks (Assertions)
+		assert_eq!(200, response.status().as_u16());
+		let body = response.into_body().try_into_bytes().unwrap();
+		let response_thing: Thing = serde_json::from_slice(&body)?;
+		assert_eq!(response_thing.description, None);
+		let body = empty_response.into_body().try_into_bytes().unwrap();
+		let empty_thing: Thing = serde_json::from_slice(&body)?;
+		assert_eq!(empty_thing.description, None);
+
+		// Read back from the database
+		let read_thing = things::get_by_id(&response_thing.id, &database).await?;
+		assert_eq!(read_thing.description, None);
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn create_a_thing_with_invalid_name(database: sqlx::Pool<sqlx::Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let form = Form(
+			ThingFormData { name: "{invalid}".to_string(), description: Some(Sentence(3..7).fake()), version: None }
+		);
+		let repository = thing_repository(&database);
+
+		//-- Execute Function (Act)
+		let error = create(actix_web::test::TestRequest::default().to_http_request(), form, repository).await.unwrap_err();
+		let response = actix_web::ResponseError::error_response(&error);
+
+		//-- Checks (Assertions)
+		// Check http status is unprocessable entity (422)
+		assert_eq!(422, response.status().as_u16());
+		// Check the body is a json error listing the invalid field
+		let body = response.into_body().try_into_bytes().unwrap();
+		let response_json: serde_json::Value = serde_json::from_slice(&body)?;
+		assert_eq!(response_json["code"], "FormValidationError");
+		assert_eq!(
+			response_json["errors"],
+			serde_json::json!({ "name": "\"{invalid}\" is not a valid Thing name." })
+		);
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn create_a_thing_lists_every_invalid_field(database: sqlx::Pool<sqlx::Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let form = Form(
+			ThingFormData { name: "{invalid}".to_string(), description: Some("<invalid>".to_string()), version: None }
+		);
+		let repository = thing_repository(&database);
 
-		// Check return body json equals query name and description
-		let body = response.into_body().try_into_bytes().unwrap();
-		// pin!(body);
-		let response_thing: Thing = serde_json::from_slice(&body).unwrap();
+		//-- Execute Function (Act)
+		let error = create(actix_web::test::TestRequest::default().to_http_request(), form, repository).await.unwrap_err();
+		let response = actix_web::ResponseError::error_response(&error);
 
-		assert_eq!(&query_name, response_thing.name.as_ref());
-		assert_eq!(&query_description, response_thing.description.unwrap().as_ref());
+		//-- Checks (Assertions)
+		assert_eq!(422, response.status().as_u16());
+		let body = response.into_body().try_into_bytes().unwrap();
+		let response_json: serde_json::Value = serde_json::from_slice(&body)?;
+		assert_eq!(
+			response_json["errors"],
+			serde_json::json!({
+				"name": "\"{invalid}\" is not a valid Thing name.",
+				"description": "\"<invalid>\" is not a valid Thing description.",
+			})
+		);
+		// Nothing is inserted
+		assert_eq!(things::count_all(&database).await?, 0);
 
 		Ok(())
 	}
@@ -259,11 +1560,11 @@ pub mod tests {
 		for _count in 0..random_count {
 			// Create a test instance
 			let test_thing = create_random_test_thing().await?;
-			// Add Thing to database
-			insert(&test_thing, &database).await?;
-			// Add thing to vector
-			test_vec.push(test_thing);
+			// Add Thing to database and vector
+			test_vec.push(insert(&test_thing, &database).await?);
 		}
+		// Default sort is newest first
+		test_vec.sort_by_key(|thing| std::cmp::Reverse((thing.created_at, thing.id)));
 		// println!("{test_vec:#?}");
 
 		//-- Execute Function (Act)
@@ -273,31 +1574,38 @@ pub mod tests {
 		let random_offset = (0..random_count).fake::<i64>();
 		// Build URL parameters
 		let web_parameters = web::Query( ThingsParameters {
-			id: None,
+			ids: None,
 			limit: Some(random_limit),
-			offset: Some(random_offset)
+			offset: Some(random_offset),
+			name: None,
+			q: None,
+			sort: None,
+			status: None,
+			include_deleted: None,
+			created_after: None,
+			created_before: None
 		});
 		// Wrap database around Actix Data type
 		let pool = Data::new(database.clone());
 		// Gat HTTP response
-		let response = read_index(web_parameters, pool).await?;
+		let response = read_index(actix_web::test::TestRequest::default().to_http_request(), web_parameters, Data::new(PaginationSettings::default()), pool).await?;
 		// println!("{response:#?}");
 		// Unwrap response to get HTTP Response body
 		let body = response.into_body().try_into_bytes().unwrap();
 		// pin!(body);
 		// println!("{body:#?}");
-		let response_things: Vec<Thing> = serde_json::from_slice(&body).unwrap();
+		let response_page: Paginated<Thing> = serde_json::from_slice(&body).unwrap();
+		let response_things = response_page.data;
 		// println!("{response_things:#?}");
 
 		//-- Checks (Assertions)
 		// How random Things will there be based on limit, with end case
 		let count_less_offset: i64 = random_count - random_offset;
-		let expected_records: i64;
-		if count_less_offset <  random_limit {
-			expected_records = count_less_offset
+		let expected_records: i64 = if count_less_offset <  random_limit {
+			count_less_offset
 		} else {
-			expected_records = random_limit
-		}
+			random_limit
+		};
 
 		let random_vec_index: i64 = (1..expected_records).fake::<i64>() - 1;
 		let random_test_vec_index = random_offset + random_vec_index;
@@ -305,6 +1613,9 @@ pub mod tests {
 		let random_test_thing = &test_vec[random_test_vec_index as usize];
 
 		assert_eq!(response_things.len() as i64, expected_records);
+		assert_eq!(response_page.total, random_count);
+		assert_eq!(response_page.limit, random_limit);
+		assert_eq!(response_page.offset, random_offset);
 		assert_eq!(random_record_thing.id, random_test_thing.id);
 		assert_eq!(random_record_thing.name, random_test_thing.name);
 		assert_eq!(random_record_thing.description, random_test_thing.description);
@@ -321,24 +1632,273 @@ pub mod tests {
 	}
 
 	#[sqlx::test]
-	async fn read_thing_by_id(database: sqlx::Pool<sqlx::Postgres>) -> Result<()> {
+	async fn get_thing_index_as_ndjson(database: sqlx::Pool<sqlx::Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let mut test_vec: Vec<Thing> = Vec::new();
+		for _count in 0..4 {
+			let test_thing = create_random_test_thing().await?;
+			test_vec.push(insert(&test_thing, &database).await?);
+		}
+
+		//-- Execute Function (Act)
+		let request = actix_web::test::TestRequest::default()
+			.insert_header((header::ACCEPT, NDJSON_CONTENT_TYPE))
+			.to_http_request();
+		let web_parameters = web::Query::<ThingsParameters>::from_query("")?;
+		let pool = Data::new(database.clone());
+		let response = read_index(request, web_parameters, Data::new(PaginationSettings::default()), pool).await?;
+
+		//-- Checks (Assertions)
+		assert_eq!(response.headers().get(header::CONTENT_TYPE).unwrap(), NDJSON_CONTENT_TYPE);
+		assert_eq!(response.headers().get(header::VARY).unwrap(), "Accept, Time-Zone");
+		let body = actix_web::body::to_bytes(response.into_body()).await?;
+		let response_things = std::str::from_utf8(&body)?
+			.lines()
+			.map(serde_json::from_str::<Thing>)
+			.collect::<core::result::Result<Vec<Thing>, _>>()?;
+		assert_eq!(response_things.len(), test_vec.len());
+		assert!(response_things
+			.iter()
+			.all(|thing| test_vec.iter().any(|test_thing| test_thing.id == thing.id)));
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn get_thing_index_as_json_when_ndjson_is_refused(database: sqlx::Pool<sqlx::Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let test_thing = create_random_test_thing().await?;
+		insert(&test_thing, &database).await?;
+
+		//-- Execute Function (Act)
+		let request = actix_web::test::TestRequest::default()
+			.insert_header((header::ACCEPT, "application/x-ndjson;q=0, application/json"))
+			.to_http_request();
+		let web_parameters = web::Query::<ThingsParameters>::from_query("")?;
+		let pool = Data::new(database.clone());
+		let response = read_index(request, web_parameters, Data::new(PaginationSettings::default()), pool).await?;
+
+		//-- Checks (Assertions)
+		assert_eq!(response.headers().get(header::CONTENT_TYPE).unwrap(), "application/json");
+		let body = response.into_body().try_into_bytes().unwrap();
+		let response_body: serde_json::Value = serde_json::from_slice(&body)?;
+		assert_eq!(response_body["total"], 1);
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn get_thing_index_created_within_range(database: sqlx::Pool<sqlx::Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		use chrono::TimeZone;
+		for day in 1..=4 {
+			let created_at = Utc.with_ymd_and_hms(2024, 1, day, 12, 0, 0).unwrap();
+			let test_thing = ThingBuilder::new(ThingName::parse(format!("Thing {day}"))?)
+				.description(ThingDescription::parse(Sentence(3..7).fake::<String>())?)
+				.created_at(created_at)
+				.updated_at(created_at)
+				.build()?;
+			insert(&test_thing, &database).await?;
+		}
+
+		//-- Execute Function (Act)
+		let web_parameters = web::Query::<ThingsParameters>::from_query(
+			"created_after=2024-01-02T00:00:00Z&created_before=2024-01-03T23:59:59Z",
+		)?;
+		let pool = Data::new(database.clone());
+		let response = read_index(actix_web::test::TestRequest::default().to_http_request(), web_parameters, Data::new(PaginationSettings::default()), pool).await?;
+		let body = response.into_body().try_into_bytes().unwrap();
+		let response_page: Paginated<Thing> = serde_json::from_slice(&body).unwrap();
+
+		//-- Checks (Assertions)
+		assert_eq!(response_page.total, 2);
+		assert_eq!(response_page.data.len(), 2);
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn get_thing_index_by_ids(database: sqlx::Pool<sqlx::Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		// Add some Things to the database, only some of which are requested
+		let mut test_vec: Vec<Thing> = Vec::new();
+		for _count in 0..5 {
+			let test_thing = create_random_test_thing().await?;
+			test_vec.push(insert(&test_thing, &database).await?);
+		}
+		let requested = &test_vec[1..4];
+		let ids = requested
+			.iter()
+			.map(|thing| thing.id.to_string())
+			.collect::<Vec<String>>()
+			.join(",");
+
+		//-- Execute Function (Act)
+		let web_parameters = web::Query( ThingsParameters {
+			ids: Some(ids),
+			limit: None,
+			offset: None,
+			name: None,
+			q: None,
+			sort: None,
+			status: None,
+			include_deleted: None,
+			created_after: None,
+			created_before: None
+		});
+		let pool = Data::new(database.clone());
+		let response = read_index(actix_web::test::TestRequest::default().to_http_request(), web_parameters, Data::new(PaginationSettings::default()), pool).await?;
+		let body = response.into_body().try_into_bytes().unwrap();
+		let response_page: Paginated<Thing> = serde_json::from_slice(&body).unwrap();
+
+		//-- Checks (Assertions)
+		// Default sort is newest first
+		let mut expected_ids = requested.to_vec();
+		expected_ids.sort_by_key(|thing| std::cmp::Reverse((thing.created_at, thing.id)));
+		let expected_ids: Vec<Uuid> = expected_ids.iter().map(|thing| thing.id).collect();
+		let response_ids: Vec<Uuid> = response_page.data.iter().map(|thing| thing.id).collect();
+
+		assert_eq!(response_ids, expected_ids);
+		assert_eq!(response_page.total, 3);
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn get_thing_index_by_ids_preserves_pagination(
+		database: sqlx::Pool<sqlx::Postgres>
+	) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let mut test_vec: Vec<Thing> = Vec::new();
+		for _count in 0..5 {
+			let test_thing = create_random_test_thing().await?;
+			test_vec.push(insert(&test_thing, &database).await?);
+		}
+		// Default sort is newest first
+		test_vec.sort_by_key(|thing| std::cmp::Reverse((thing.created_at, thing.id)));
+		let ids = test_vec
+			.iter()
+			.map(|thing| thing.id.to_string())
+			.collect::<Vec<String>>()
+			.join(",");
+
+		//-- Execute Function (Act)
+		let web_parameters = web::Query( ThingsParameters {
+			ids: Some(ids),
+			limit: Some(2),
+			offset: Some(1),
+			name: None,
+			q: None,
+			sort: None,
+			status: None,
+			include_deleted: None,
+			created_after: None,
+			created_before: None
+		});
+		let pool = Data::new(database.clone());
+		let response = read_index(actix_web::test::TestRequest::default().to_http_request(), web_parameters, Data::new(PaginationSettings::default()), pool).await?;
+		let body = response.into_body().try_into_bytes().unwrap();
+		let response_page: Paginated<Thing> = serde_json::from_slice(&body).unwrap();
+
+		//-- Checks (Assertions)
+		let response_ids: Vec<Uuid> = response_page.data.iter().map(|thing| thing.id).collect();
+
+		assert_eq!(response_ids, vec![test_vec[1].id, test_vec[2].id]);
+		assert_eq!(response_page.total, 5);
+		assert_eq!(response_page.limit, 2);
+		assert_eq!(response_page.offset, 1);
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn get_thing_index_by_ids_with_invalid_id(
+		database: sqlx::Pool<sqlx::Postgres>
+	) -> Result<()> {
 		//-- Setup and Fixtures (Arrange)
-		// Create a test Thing instance
 		let test_thing = create_random_test_thing().await?;
-		// Add Thing to database
 		insert(&test_thing, &database).await?;
+		let ids = format!("{},not-a-uuid", test_thing.id);
 
 		//-- Execute Function (Act)
-		// Build web parameters
 		let web_parameters = web::Query( ThingsParameters {
-			id: Some(test_thing.id),
+			ids: Some(ids),
 			limit: None,
-			offset: None
+			offset: None,
+			name: None,
+			q: None,
+			sort: None,
+			status: None,
+			include_deleted: None,
+			created_after: None,
+			created_before: None
 		});
-		// Wrap database in Actix Data Type
 		let pool = Data::new(database.clone());
+		let error = read_index(actix_web::test::TestRequest::default().to_http_request(), web_parameters, Data::new(PaginationSettings::default()), pool).await.unwrap_err();
+
+		//-- Checks (Assertions)
+		assert_eq!(400, actix_web::ResponseError::status_code(&error).as_u16());
+		assert!(matches!(
+			error,
+			crate::error::Error::Handlers(handlers::Error::ThingIdInvalid { ref id }) if id == "not-a-uuid"
+		));
+		assert!(error.to_string().contains("not-a-uuid"));
+
+		Ok(())
+	}
+
+	#[test]
+	fn page_bounds_default_and_clamp_the_limit() -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let pagination = PaginationSettings::default();
+
+		//-- Execute Function (Act)
+		let default_bounds = page_bounds(None, None, &pagination)?;
+		let clamped_bounds = page_bounds(Some(pagination.max_limit + 1), Some(5), &pagination)?;
+
+		//-- Checks (Assertions)
+		assert_eq!(default_bounds, (pagination.default_limit, 0));
+		assert_eq!(clamped_bounds, (pagination.max_limit, 5));
+
+		Ok(())
+	}
+
+	#[test]
+	fn page_bounds_reject_negative_values() {
+		//-- Setup and Fixtures (Arrange)
+		let pagination = PaginationSettings::default();
+
+		//-- Execute Function (Act)
+		let limit_error = page_bounds(Some(-1), None, &pagination).unwrap_err();
+		let offset_error = page_bounds(None, Some(-1), &pagination).unwrap_err();
+
+		//-- Checks (Assertions)
+		assert_eq!(400, actix_web::ResponseError::status_code(&limit_error).as_u16());
+		assert!(matches!(
+			limit_error,
+			crate::error::Error::Handlers(handlers::Error::ParameterNegative { name: "limit", value: -1 })
+		));
+		assert!(matches!(
+			offset_error,
+			crate::error::Error::Handlers(handlers::Error::ParameterNegative { name: "offset", value: -1 })
+		));
+	}
+
+	#[sqlx::test]
+	async fn read_thing_by_id(database: sqlx::Pool<sqlx::Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		// Create a test Thing instance
+		let test_thing = create_random_test_thing().await?;
+		// Add Thing to database
+		insert(&test_thing, &database).await?;
+
+		//-- Execute Function (Act)
+		// Build web path
+		let path = web::Path::from(test_thing.id);
+		// Wrap database in a Thing repository
+		let repository = thing_repository(&database);
 		// Execute read
-		let response = read_by_id(web_parameters, pool).await?;
+		let response = read_by_id(actix_web::test::TestRequest::default().to_http_request(), path, repository).await?;
 
 		//-- Checks (Assertions)
 		// Check http response is success
@@ -367,27 +1927,105 @@ pub mod tests {
 	}
 
 	#[sqlx::test]
-	async fn read_error_no_id(database: sqlx::Pool<sqlx::Postgres>) -> Result<()> {
+	async fn read_thing_with_malformed_id(database: sqlx::Pool<sqlx::Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let app = actix_web::test::init_service(
+			actix_web::App::new()
+				.app_data(thing_repository(&database))
+				.app_data(web::PathConfig::default().error_handler(handlers::path_error_handler))
+				.route("/things/{thing_id}", web::get().to(read_by_id)),
+		)
+		.await;
+
+		//-- Execute Function (Act)
+		let request = actix_web::test::TestRequest::get().uri("/things/not-a-uuid").to_request();
+		let response = actix_web::test::call_service(&app, request).await;
+
+		//-- Checks (Assertions)
+		// Check http status is bad request (400)
+		assert_eq!(400, response.status().as_u16());
+		// Check the body names the malformed path parameter
+		let body = actix_web::test::read_body(response).await;
+		let response_json: serde_json::Value = serde_json::from_slice(&body)?;
+		assert_eq!(response_json["code"], "PathParameterInvalid");
+		assert_eq!(
+			response_json["error"],
+			"\"not-a-uuid\" is not a valid `thing_id` path parameter."
+		);
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn read_unknown_thing_by_id(database: sqlx::Pool<sqlx::Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		// Random Uuid that is not in the database
+		let unknown_id = Uuid::now_v7();
+
+		//-- Execute Function (Act)
+		let path = web::Path::from(unknown_id);
+		let repository = thing_repository(&database);
+		let error = read_by_id(actix_web::test::TestRequest::default().to_http_request(), path, repository).await.unwrap_err();
+		let response = actix_web::ResponseError::error_response(&error);
+
+		//-- Checks (Assertions)
+		// Check http status is not found (404)
+		assert_eq!(404, response.status().as_u16());
+		// Check the body is the json not found error
+		let body = response.into_body().try_into_bytes().unwrap();
+		let response_json: serde_json::Value = serde_json::from_slice(&body)?;
+		assert_eq!(response_json["code"], "ThingUnknownId");
+		assert_eq!(
+			response_json["error"],
+			"There is no Thing associated with the provided id."
+		);
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn read_thing_by_slug(database: sqlx::Pool<sqlx::Postgres>) -> Result<()> {
 		//-- Setup and Fixtures (Arrange)
-		// Create a test Thing instance
 		let test_thing = create_random_test_thing().await?;
-		// Add Thing to database
-		insert(&test_thing, &database).await?;
+		let test_thing = insert(&test_thing, &database).await?;
+		let slug = test_thing.slug.clone().unwrap();
 
 		//-- Execute Function (Act)
-		// Build web parameters
-		let web_parameters = web::Query( ThingsParameters {
-			id: None,
-			limit: None,
-			offset: None
-		});
-		// Wrap database in Actix Data Type
+		let path = web::Path::from(String::from(slug));
 		let pool = Data::new(database.clone());
-		// Execute read
-		let record = read_by_id(web_parameters, pool).await.unwrap_err();
+		let response = read_by_slug(actix_web::test::TestRequest::default().to_http_request(), path, pool).await?;
+
+		//-- Checks (Assertions)
+		assert_eq!(200, response.status().as_u16());
+		let body = response.into_body().try_into_bytes().unwrap();
+		let response_thing: Thing = serde_json::from_slice(&body).unwrap();
+		assert_eq!(response_thing.id, test_thing.id);
+		assert_eq!(response_thing.slug, test_thing.slug);
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn read_things_by_ids_skips_unknown_ids(database: sqlx::Pool<sqlx::Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let mut test_vec: Vec<Thing> = Vec::new();
+		for _count in 0..2 {
+			let test_thing = create_random_test_thing().await?;
+			test_vec.push(insert(&test_thing, &database).await?);
+		}
+		let ids = format!("{},{},{}", test_vec[0].id, Uuid::now_v7(), test_vec[1].id);
+
+		//-- Execute Function (Act)
+		let web_parameters = web::Query(ByIdsParameters { ids });
+		let pool = Data::new(database.clone());
+		let response = read_by_ids(actix_web::test::TestRequest::default().to_http_request(), web_parameters, pool).await?;
 
 		//-- Checks (Assertions)
-		assert!(matches!(record, crate::error::Error::ParameterMissing));
+		assert_eq!(200, response.status().as_u16());
+		let body = response.into_body().try_into_bytes().unwrap();
+		let response_things: Vec<Thing> = serde_json::from_slice(&body).unwrap();
+		let response_ids: Vec<Uuid> = response_things.iter().map(|thing| thing.id).collect();
+		assert_eq!(response_ids, vec![test_vec[0].id, test_vec[1].id]);
 
 		Ok(())
 	}
@@ -401,25 +2039,22 @@ pub mod tests {
 		insert(&test_thing, &database).await?;
 
 		//-- Execute Function (Act)
-		// Build web parameters
-		let parameters = web::Query( ThingsParameters {
-			id: Some(test_thing.id),
-			limit: None,
-			offset: None
-		});
+		// Build web path
+		let path = web::Path::from(test_thing.id);
 		// Build web form
 		let updated_name: String = Word().fake();
 		let updated_description: String = Sentence(3..7).fake();
 		let form = Form(
 			ThingFormData {
 				name: updated_name.clone(),
-				description: updated_description.clone(),
+				description: Some(updated_description.clone()),
+				version: Some(test_thing.version),
 			}
 		);
-		// Build database pool
-		let pool = Data::new(database.clone());
+		// Build the Thing repository
+		let repository = thing_repository(&database);
 		// Update Thing
-		let response = update_by_id(parameters, form, pool).await?;
+		let response = update_by_id(path, form, repository).await?;
 
 		//-- Checks (Assertions)
 		// Check http response is success
@@ -462,30 +2097,117 @@ pub mod tests {
 		insert(&test_thing, &database).await?;
 
 		//-- Execute Function (Act)
-		// Build web parameters
-		let parameters = web::Query( ThingsParameters {
-			id: Some(test_thing.id),
-			limit: None,
-			offset: None
-		});
-		// Build database pool
-		let pool = Data::new(database.clone());
-		// Update Thing
-		let response = delete_by_id(parameters, pool).await?;
+		// Build web path
+		let path = web::Path::from(test_thing.id);
+		// Build the Thing repository
+		let repository = thing_repository(&database);
+		// Delete Thing
+		let response = delete_by_id(path, repository).await?;
+
+		//-- Checks (Assertions)
+		// Check http status is no content (204)
+		assert_eq!(204, response.status().as_u16());
+		// Check the Thing is no longer in the database
+		let count = crate::services::things::count_all(&database).await?;
+		assert_eq!(count, 0);
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn delete_unknown_thing_by_id(database: sqlx::Pool<sqlx::Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		// Create a test Thing instance, but do not add it to the database
+		let test_thing = create_random_test_thing().await?;
+
+		//-- Execute Function (Act)
+		let path = web::Path::from(test_thing.id);
+		let repository = thing_repository(&database);
+		let error = delete_by_id(path, repository).await.unwrap_err();
+		let response = actix_web::ResponseError::error_response(&error);
+
+		//-- Checks (Assertions)
+		// Check http status is not found (404)
+		assert_eq!(404, response.status().as_u16());
+		assert!(matches!(
+			error,
+			crate::error::Error::Services(services::Error::ThingUnknownId)
+		));
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn available_for_an_unused_name(database: sqlx::Pool<sqlx::Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let name: String = Word().fake();
+		let parameters = web::Query(AvailableParameters { name });
+
+		//-- Execute Function (Act)
+		let response = available(parameters, Data::new(database)).await?;
 
 		//-- Checks (Assertions)
-		// Check http response is success
-		assert!(response.status().is_success());
-		// Check http status is ok (200)
 		assert_eq!(200, response.status().as_u16());
-		// Unwrap response to get HTTP Response body
-		let body = response.into_body().try_into_bytes().unwrap();
-		// Check 1 thing was deleted
-		// Serialise bytes into Thing
-		let number_deleted: u64 = serde_json::from_slice(&body).unwrap();
-		assert_eq!(number_deleted, 1);
+		let body: ThingNameAvailable = serde_json::from_slice(&response.into_body().try_into_bytes().unwrap())?;
+		assert_eq!(body, ThingNameAvailable { available: true });
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn not_available_for_a_taken_name(database: sqlx::Pool<sqlx::Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let test_thing = create_random_test_thing().await?;
+		insert(&test_thing, &database).await?;
+		let parameters = web::Query(AvailableParameters { name: test_thing.name.as_ref().to_string() });
+
+		//-- Execute Function (Act)
+		let response = available(parameters, Data::new(database)).await?;
+
+		//-- Checks (Assertions)
+		assert_eq!(200, response.status().as_u16());
+		let body: ThingNameAvailable = serde_json::from_slice(&response.into_body().try_into_bytes().unwrap())?;
+		assert_eq!(body, ThingNameAvailable { available: false });
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn not_available_for_a_case_variant_or_soft_deleted_name(database: sqlx::Pool<sqlx::Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let taken = ThingBuilder::new(ThingName::parse("Morning Coffee")?).build()?;
+		insert(&taken, &database).await?;
+		let deleted = ThingBuilder::new(ThingName::parse("Evening Tea")?).build()?;
+		insert(&deleted, &database).await?;
+		things::soft_delete_by_id(&deleted.id, &database).await?;
+
+		//-- Execute Function (Act)
+		let mut bodies = Vec::new();
+		for name in ["morning COFFEE", "Evening Tea"] {
+			let parameters = web::Query(AvailableParameters { name: name.to_string() });
+			let response = available(parameters, Data::new(database.clone())).await?;
+			let body: ThingNameAvailable = serde_json::from_slice(&response.into_body().try_into_bytes().unwrap())?;
+			bodies.push(body);
+		}
+
+		//-- Checks (Assertions)
+		// Both names would be a conflict on create
+		assert!(bodies.iter().all(|body| !body.available));
 
 		Ok(())
 	}
 
-}
\ No newline at end of file
+	#[sqlx::test]
+	async fn available_for_an_invalid_name_returns_400(database: sqlx::Pool<sqlx::Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let parameters = web::Query(AvailableParameters { name: "{invalid}".to_string() });
+
+		//-- Execute Function (Act)
+		let error = available(parameters, Data::new(database)).await.unwrap_err();
+
+		//-- Checks (Assertions)
+		assert_eq!(400, actix_web::ResponseError::status_code(&error).as_u16());
+
+		Ok(())
+	}
+}
@@ -0,0 +1,74 @@
+//-- ./src/handlers/verify.rs
+
+//! Email verification handler
+//!
+//! # Verify Handler
+//!
+//! This module handles requests to `/verify`, following the link emailed to a
+//! user with their verification token.
+
+use crate::prelude::*;
+use crate::services::verification;
+
+use actix_web::web::{self, Data};
+use actix_web::HttpResponse;
+use sqlx::PgPool;
+
+/// Email verification URL parameters.
+#[derive(serde::Deserialize, Debug)]
+pub struct VerifyParameters {
+	/// The verification token from the emailed link
+	token: String,
+}
+
+/// Handle `[GET] api/v1/verify?token=` requests
+///
+/// # Verify Email
+///
+/// Consume the verification token and mark its user verified, responding with
+/// `200 OK`, or `400 Bad Request` if the token is unknown, used or expired.
+///
+/// # Parameter
+///
+/// * `parameters` - The `token` URL parameter
+/// * `pool` - an Actix web data wrapper around a Postgres connection pool
+/// ---
+#[tracing::instrument(
+	name = "GET verify email handler."
+	skip(parameters, pool)
+)]
+pub async fn verify(
+	parameters: web::Query<VerifyParameters>,
+	pool: Data<PgPool>
+) -> Result<HttpResponse> {
+	verification::verify(&parameters.token, &pool).await?;
+
+	Ok(HttpResponse::Ok().finish())
+}
+
+//-- Unit Tests
+#[cfg(test)]
+pub mod tests {
+	// Bring module functions into test scope
+	use super::*;
+
+	// Override with more flexible error
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>;
+
+	use actix_web::ResponseError;
+
+	#[sqlx::test]
+	async fn verify_unknown_token_returns_400(database: sqlx::Pool<sqlx::Postgres>) -> Result<()> {
+		//-- Execute Function (Act)
+		let web_parameters = web::Query(VerifyParameters { token: "not-a-token".to_string() });
+		let pool = Data::new(database.clone());
+		let error = verify(web_parameters, pool).await.unwrap_err();
+
+		//-- Checks (Assertions)
+		assert_eq!(400, error.status_code().as_u16());
+		assert_eq!(error.code(), "VerificationTokenInvalid");
+
+		Ok(())
+	}
+}
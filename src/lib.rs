@@ -1,11 +1,14 @@
 #![doc = include_str!("../README.md")]
 
 pub mod api;
+pub mod api_key;
 pub mod configuration;
 pub mod domain;
 pub mod error;
 pub mod handlers;
 pub mod prelude;
+pub mod rate_limit;
+pub mod request_id;
 pub mod routes;
 pub mod services;
 pub mod startup;
@@ -1,30 +1,38 @@
 // -- ./src/main.rs
 
-use crate::prelude::*;
 use crate::configuration::Configuration;
 
+use personal_ledger_server::prelude::*;
 use personal_ledger_server::{configuration, startup::{self, Application}, telemetry};
 
 //-- Re-export modules
-mod error;
-mod prelude;
 mod utils;
 
+/// Command line flag to run the database migrations and exit, without starting
+/// the server
+const MIGRATE_FLAG: &str = "--migrate";
+
 /// The API main entry function 
 /// 
 /// Main functions are not async so we need some magic with `#[actix_web::main]``
 #[actix_web::main]
 async fn main() -> Result<()> {
     // Parse configuration files
-    let configuration: Configuration =
+    let mut configuration: Configuration =
         Configuration::parse()
         .expect("Failed to read configuration.");
+    configuration.validate().expect("Invalid configuration.");
+    let migrate_only = std::env::args().any(|argument| argument == MIGRATE_FLAG);
+    if migrate_only {
+        // Connecting the pool runs the migrations
+        configuration.database.run_migrations = true;
+    }
 
     // Build tracing subscriber
     let tracing_subscriber = telemetry::get_tracing_subscriber(
         "personal_ledger_server".into(),
         std::io::stdout,
-        configuration.application.runtime_environment,
+        configuration.application.log_format,
         configuration.application.log_level
     );
     telemetry::init_tracing(
@@ -35,6 +43,9 @@ async fn main() -> Result<()> {
     let database_pool = startup::get_connection_pool(&configuration.database)
         .await
         .expect("Error connecting to database.");
+    if migrate_only {
+        return Ok(());
+    }
 
     let application = Application::build(configuration, database_pool)
         .await
@@ -0,0 +1,146 @@
+// -- ./src/rate_limit.rs
+
+//! Limit the number of requests a client can make
+//!
+//! # Rate Limit Middleware
+//!
+//! The `rate_limit` middleware keeps a token bucket per client, keyed by the
+//! `ApiKey` id of a request authenticated by the `api_key` middleware and the
+//! peer IP address otherwise. The raw `X-Api-Key` header is never used, so a
+//! client can not get a new bucket by sending a new key.
+//! Each bucket holds up to `rate_limit_per_minute` tokens and refills at that
+//! rate. A request takes a token, and a request finding the bucket empty is a
+//! `429 Too Many Requests` with a `Retry-After` header of the seconds until the
+//! next token.
+
+use crate::error::Error;
+use crate::services::api_keys::ApiKey;
+
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::middleware::Next;
+use actix_web::web::Data;
+use actix_web::{HttpMessage, HttpRequest};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Number of buckets held before full buckets are dropped
+const PRUNE_BUCKETS_OVER: usize = 10_000;
+
+/// A client token bucket
+struct Bucket {
+	tokens: f64,
+	refilled_at: Instant,
+}
+
+/// Token buckets for each client, shared by the workers
+pub struct RateLimiter {
+	capacity: f64,
+	tokens_per_second: f64,
+	buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+impl RateLimiter {
+	/// Create a limiter allowing `per_minute` requests a minute from each
+	/// client, `0` disables the limit
+	pub fn new(per_minute: u32) -> Self {
+		Self {
+			capacity: f64::from(per_minute),
+			tokens_per_second: f64::from(per_minute) / 60.0,
+			buckets: Mutex::new(HashMap::new()),
+		}
+	}
+
+	/// Take a token from the `client` bucket, returning the time until the
+	/// next token if the bucket is empty
+	pub fn check(&self, client: &str) -> Result<(), Duration> {
+		if self.capacity == 0.0 {
+			return Ok(());
+		}
+
+		let now = Instant::now();
+		let mut buckets = self.buckets.lock().expect("Rate limiter lock poisoned");
+		if buckets.len() > PRUNE_BUCKETS_OVER {
+			buckets.retain(|_, bucket| self.refill(bucket, now) < self.capacity);
+		}
+
+		let bucket = buckets
+			.entry(client.to_string())
+			.or_insert(Bucket { tokens: self.capacity, refilled_at: now });
+		let tokens = self.refill(bucket, now);
+		if tokens < 1.0 {
+			return Err(Duration::from_secs_f64((1.0 - tokens) / self.tokens_per_second));
+		}
+		bucket.tokens = tokens - 1.0;
+		bucket.refilled_at = now;
+
+		Ok(())
+	}
+
+	/// The tokens `bucket` holds at `now`
+	fn refill(&self, bucket: &Bucket, now: Instant) -> f64 {
+		let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+		(bucket.tokens + elapsed * self.tokens_per_second).min(self.capacity)
+	}
+}
+
+/// The key of the client making `request`, the authenticated `ApiKey` id or
+/// the peer IP address. The `api_key` middleware must have run first, so an
+/// authenticated `ApiKey` is in the request extensions.
+pub fn client_key(request: &HttpRequest) -> String {
+	if let Some(api_key) = request.extensions().get::<ApiKey>() {
+		return format!("key:{}", api_key.id);
+	}
+
+	let ip = request
+		.peer_addr()
+		.map(|address| address.ip().to_string())
+		.unwrap_or_default();
+	format!("ip:{ip}")
+}
+
+/// Middleware rejecting requests from clients over the `RateLimiter` limit
+pub async fn rate_limit(
+	request: ServiceRequest,
+	next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+	if let Some(limiter) = request.app_data::<Data<RateLimiter>>() {
+		limiter.check(&client_key(request.request())).map_err(|retry_after| Error::RateLimited {
+			// Round up, so a client retrying after the header gets a token
+			retry_after_seconds: retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0),
+		})?;
+	}
+
+	next.call(request).await
+}
+
+//-- Unit Tests
+#[cfg(test)]
+pub mod tests {
+	// Bring module functions into test scope
+	use super::*;
+
+	#[test]
+	fn requests_over_the_limit_are_rejected() {
+		let limiter = RateLimiter::new(2);
+
+		assert!(limiter.check("ip:127.0.0.1").is_ok());
+		assert!(limiter.check("ip:127.0.0.1").is_ok());
+		let retry_after = limiter.check("ip:127.0.0.1").unwrap_err();
+		// One token refills every 30 seconds
+		assert!(retry_after <= Duration::from_secs(30));
+		assert!(retry_after > Duration::from_secs(29));
+		// Other clients have their own bucket
+		assert!(limiter.check("ip:10.0.0.1").is_ok());
+	}
+
+	#[test]
+	fn a_zero_limit_is_disabled() {
+		let limiter = RateLimiter::new(0);
+
+		for _ in 0..100 {
+			assert!(limiter.check("ip:127.0.0.1").is_ok());
+		}
+	}
+}
@@ -0,0 +1,89 @@
+// -- ./src/request_id.rs
+
+//! Correlate requests across services with an `X-Request-Id` header
+//!
+//! # Request Id Middleware
+//!
+//! The `request_id` middleware reads the `X-Request-Id` header of an incoming
+//! request, generating a Uuid v7 when it is absent, stores it in the request
+//! extensions and echoes it back on the response. `RequestIdRootSpanBuilder`
+//! attaches it to the `TracingLogger` root span as the `x_request_id` field, so
+//! every record logged while handling the request carries it.
+
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::header::{HeaderName, HeaderValue};
+use actix_web::middleware::Next;
+use actix_web::HttpMessage;
+use tracing::Span;
+use tracing_actix_web::{DefaultRootSpanBuilder, RootSpanBuilder};
+use uuid::Uuid;
+
+/// Header the request id is read from and echoed back on
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// The id of a request, as supplied by the client or generated
+#[derive(Clone, Debug, PartialEq)]
+pub struct RequestId(String);
+
+impl RequestId {
+	/// Returns the supplied `X-Request-Id` header value, or a new Uuid v7 if the
+	/// header is missing, empty or not visible ASCII.
+	fn from_request(request: &ServiceRequest) -> Self {
+		request
+			.headers()
+			.get(REQUEST_ID_HEADER)
+			.and_then(|value| value.to_str().ok())
+			.map(str::trim)
+			.filter(|value| !value.is_empty())
+			.map(|value| Self(value.to_string()))
+			.unwrap_or_else(|| Self(Uuid::now_v7().to_string()))
+	}
+}
+
+impl std::fmt::Display for RequestId {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self.0)
+	}
+}
+
+/// Middleware storing the `RequestId` in the request extensions and echoing it
+/// back in the response `X-Request-Id` header
+///
+/// It must wrap `TracingLogger`, so the id is set before the root span is built.
+pub async fn request_id(
+	request: ServiceRequest,
+	next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+	let request_id = RequestId::from_request(&request);
+	request.extensions_mut().insert(request_id.clone());
+
+	let mut response = next.call(request).await?;
+	if let Ok(value) = HeaderValue::from_str(&request_id.0) {
+		response.headers_mut().insert(REQUEST_ID_HEADER, value);
+	}
+
+	Ok(response)
+}
+
+/// `TracingLogger` root span builder adding the `RequestId` as a span field
+pub struct RequestIdRootSpanBuilder;
+
+impl RootSpanBuilder for RequestIdRootSpanBuilder {
+	fn on_request_start(request: &ServiceRequest) -> Span {
+		let request_id = request
+			.extensions()
+			.get::<RequestId>()
+			.map(ToString::to_string)
+			.unwrap_or_default();
+
+		tracing_actix_web::root_span!(request, x_request_id = %request_id)
+	}
+
+	fn on_request_end<B: MessageBody>(
+		span: Span,
+		outcome: &Result<ServiceResponse<B>, actix_web::Error>,
+	) {
+		DefaultRootSpanBuilder::on_request_end(span, outcome);
+	}
+}
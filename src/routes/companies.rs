@@ -6,21 +6,14 @@
 //! 
 //! An end point route template for /api/v1/companies
 //! 
-use actix_web::web;
-use crate::handlers;
+use super::RouteDefinition;
+use crate::handlers::companies;
 
-/// # COMPANY ROUTES
-/// 
-/// Configure Companies end point calls 
-/// 
-/// ## ATTRIBUTES
-/// 
-/// * `config`: is the Actix route config to build off
-pub fn companies(config: &mut web::ServiceConfig) {
-    config
-        .service(handlers::companies::index)
-        .service(handlers::companies::create)
-        .service(handlers::companies::read)
-        .service(handlers::companies::update)
-        .service(handlers::companies::delete);
-}
\ No newline at end of file
+/// Routes of the `/companies` end point, relative to the scope
+pub const COMPANIES_ROUTES: &[RouteDefinition] = &[
+    RouteDefinition::new("GET", "", |route| route.to(companies::index)),
+    RouteDefinition::new("POST", "", |route| route.to(companies::create)),
+    RouteDefinition::new("GET", "{company_id}", |route| route.to(companies::read)),
+    RouteDefinition::new("PUT", "{company_id}", |route| route.to(companies::update)),
+    RouteDefinition::new("DELETE", "{company_id}", |route| route.to(companies::delete)),
+];
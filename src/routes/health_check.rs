@@ -0,0 +1,11 @@
+//-- ./src/routes/health_check.rs
+
+//! End point route for /api/v1/health_check
+
+use super::RouteDefinition;
+use crate::handlers::health_check;
+
+/// Routes of the `/health_check` end point, relative to the scope
+pub const HEALTH_CHECK_ROUTES: &[RouteDefinition] = &[
+    RouteDefinition::new("GET", "", |route| route.to(health_check::index)),
+];
@@ -0,0 +1,11 @@
+//-- ./src/routes/info.rs
+
+//! End point route for /api/v1/info
+
+use super::RouteDefinition;
+use crate::handlers::info;
+
+/// Routes of the `/info` end point, relative to the scope
+pub const INFO_ROUTES: &[RouteDefinition] = &[
+    RouteDefinition::new("GET", "", |route| route.to(info::index)),
+];
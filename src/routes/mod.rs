@@ -4,12 +4,60 @@
 //! 
 //! Configure methods for each api end point.
 //! Each method calls a handler
+//! 
+//! Each end point module has one table of `RouteDefinition`s, which is both
+//! mounted on the app by `configure` and listed by `api::v1_routes`, so the
+//! listed routes are always the mounted routes.
 
-// TODO: implement a `/health_check` endpoint to provide.
+mod health_check;
+mod info;
 mod ping;
 mod things;
 mod companies;
+mod verify;
 
+pub use health_check::*;
+pub use info::*;
 pub use ping::*;
 pub use things::*;
 pub use companies::*;
+pub use verify::*;
+
+use actix_web::http::Method;
+use actix_web::{web, Route};
+
+/// A route of an end point module, relative to the module scope
+pub struct RouteDefinition {
+    /// HTTP method the route responds to, i.e. `GET`
+    pub method: &'static str,
+    /// Path of the route, relative to the module scope
+    pub path: &'static str,
+    /// Attach the route handler to an Actix route for `method`
+    pub handler: fn(Route) -> Route,
+}
+
+impl RouteDefinition {
+    /// Define a `method` route at `path`, handled by `handler`
+    pub const fn new(
+        method: &'static str,
+        path: &'static str,
+        handler: fn(Route) -> Route,
+    ) -> Self {
+        Self { method, path, handler }
+    }
+}
+
+/// Mount each of `routes` on `config`, in order, so a static path listed
+/// before a dynamic path, i.e. `count` before `{thing_id}`, is matched first
+/// 
+/// # Parameters
+/// 
+/// * `config`: is the Actix route config to build off
+/// * `routes`: the route definitions of an end point module
+pub fn configure(config: &mut web::ServiceConfig, routes: &[RouteDefinition]) {
+    for route in routes {
+        let method = Method::from_bytes(route.method.as_bytes())
+            .expect("Route method is not an HTTP method");
+        config.route(route.path, (route.handler)(web::method(method)));
+    }
+}
@@ -2,15 +2,10 @@
 
 //! End point route for /api/v1/ping
 
+use super::RouteDefinition;
 use crate::handlers::ping;
 
-use actix_web::web;
-
-/// Configure ping end point calls
-/// 
-/// # Parameters
-/// 
-/// * `config`: is the Actix route config to build off
-pub fn ping(config: &mut web::ServiceConfig) {
-    config.route("",web::get().to(ping::index));
-}
\ No newline at end of file
+/// Routes of the `/ping` end point, relative to the scope
+pub const PING_ROUTES: &[RouteDefinition] = &[
+    RouteDefinition::new("GET", "", |route| route.to(ping::index)),
+];
@@ -2,30 +2,33 @@
 
 //! A template for defining the end point route for `/api/v1/things``
 
-use crate::handlers::things;
+use super::RouteDefinition;
+use crate::handlers::{notes, thing_events, things};
 
-use actix_web::web;
-
-/// Configure endpoint routes for `things`
-/// 
-/// # Things Routes 
-/// 
-/// Define the CRUD routes for `/things`
-/// 
-/// # Parameters
+/// Routes of the `/things` end point, relative to the scope
 /// 
-/// * `config`: is the Actix route config to build off
-/// ---
-pub fn things(config: &mut web::ServiceConfig) {
-    config
-        // .service(things::index)
-        .route("", web::get().to(things::read_index))
-        // .service(things::create)
-        .route("", web::post().to(things::create))
-        // .service(things::read)
-        .route("{thing_id}", web::get().to(things::read_by_id))
-        // .service(things::update)
-        .route("{thing_id}", web::put().to(things::update_by_id))
-        // .service(things::delete);
-        .route("{thing_id}", web::delete().to(things::delete_by_id));
-}
\ No newline at end of file
+/// Define the CRUD routes for `/things`. Static paths are listed before
+/// `{thing_id}`, so they are not matched as an id.
+pub const THINGS_ROUTES: &[RouteDefinition] = &[
+    RouteDefinition::new("GET", "", |route| route.to(things::read_index)),
+    RouteDefinition::new("POST", "", |route| route.to(things::create)),
+    RouteDefinition::new("DELETE", "", |route| route.to(things::delete_by_ids)),
+    RouteDefinition::new("POST", "import", |route| route.to(things::import)),
+    RouteDefinition::new("GET", "export", |route| route.to(things::export)),
+    RouteDefinition::new("GET", "events", |route| route.to(thing_events::events)),
+    RouteDefinition::new("GET", "ws", |route| route.to(thing_events::websocket)),
+    RouteDefinition::new("GET", "autocomplete", |route| route.to(things::autocomplete)),
+    RouteDefinition::new("GET", "available", |route| route.to(things::available)),
+    RouteDefinition::new("GET", "by-slug/{slug}", |route| route.to(things::read_by_slug)),
+    RouteDefinition::new("GET", "by-ids", |route| route.to(things::read_by_ids)),
+    RouteDefinition::new("GET", "count", |route| route.to(things::count)),
+    RouteDefinition::new("GET", "{thing_id}", |route| route.to(things::read_by_id)),
+    RouteDefinition::new("PUT", "{thing_id}", |route| route.to(things::update_by_id)),
+    RouteDefinition::new("PATCH", "{thing_id}", |route| route.to(things::merge_patch_by_id)),
+    RouteDefinition::new("DELETE", "{thing_id}", |route| route.to(things::delete_by_id)),
+    RouteDefinition::new("POST", "{thing_id}/restore", |route| route.to(things::restore_by_id)),
+    // Notes sub-resource
+    RouteDefinition::new("GET", "{thing_id}/notes", |route| route.to(notes::read_index)),
+    RouteDefinition::new("POST", "{thing_id}/notes", |route| route.to(notes::create)),
+    RouteDefinition::new("DELETE", "{thing_id}/notes/{note_id}", |route| route.to(notes::delete_by_id)),
+];
@@ -0,0 +1,11 @@
+//-- ./src/routes/verify.rs
+
+//! End point route for /api/v1/verify
+
+use super::RouteDefinition;
+use crate::handlers::verify;
+
+/// Routes of the email verification `/verify` end point, relative to the scope
+pub const VERIFY_ROUTES: &[RouteDefinition] = &[
+    RouteDefinition::new("GET", "", |route| route.to(verify::verify)),
+];
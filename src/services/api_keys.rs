@@ -0,0 +1,285 @@
+// -- ./src/services/api_keys.rs
+
+//! A service for issuing, revoking and looking up API keys
+//!
+//! API keys are static secrets for service to service callers, sent in the
+//! `X-Api-Key` header. Only the SHA-256 hash of a key is stored, so a leaked
+//! database does not leak usable keys. `ApiKeyCache` keeps recent lookups in
+//! memory, so a caller making many requests does not query the database for
+//! each one.
+
+use crate::prelude::*;
+
+use chrono::prelude::Utc;
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tracing::debug;
+use uuid::Uuid;
+
+/// Length of a generated API key
+const API_KEY_LENGTH: usize = 40;
+
+/// How long a key lookup is cached for, in seconds
+pub const API_KEY_CACHE_TTL_SECONDS: u64 = 60;
+
+/// Most unknown or revoked key lookups cached at once, so a client sending
+/// many random keys can not grow the cache without bound
+pub const API_KEY_CACHE_MAX_UNKNOWN: usize = 1024;
+
+/// An active API key, without the key itself
+#[derive(Clone, Debug, PartialEq)]
+pub struct ApiKey {
+	pub id: Uuid,
+	pub name: String,
+}
+
+/// Generate a random, case sensitive alphanumeric API key
+fn generate_key() -> String {
+	rand::thread_rng()
+		.sample_iter(&Alphanumeric)
+		.map(char::from)
+		.take(API_KEY_LENGTH)
+		.collect()
+}
+
+/// The hex encoded SHA-256 hash of `key`, as stored in the `api_keys` table
+pub fn hash_key(key: &str) -> String {
+	hex::encode(Sha256::digest(key.as_bytes()))
+}
+
+/// Issue an API key named `name`, returning the key id and the key. The key is
+/// not stored, so this is the only time it is available.
+///
+/// # Parameters
+///
+/// * `name` - A name for the key, i.e. the service using it
+/// * `database` - An Sqlx database connection pool
+/// ---
+#[tracing::instrument(
+	name = "Issue an API key."
+	skip(database)
+)]
+pub async fn issue(
+	name: &str,
+	database: &sqlx::Pool<sqlx::Postgres>,
+) -> Result<(Uuid, String)> {
+	let id = Uuid::now_v7();
+	let key = generate_key();
+	sqlx::query!(
+		r#"
+			INSERT INTO api_keys (id, name, key_hash)
+			VALUES ($1, $2, $3)
+		"#,
+		id,
+		name,
+		hash_key(&key),
+	)
+	.execute(database)
+	.await?;
+	debug!("API key stored: {id}");
+
+	Ok((id, key))
+}
+
+/// Revoke the API key with `id`, returning the number of keys revoked
+///
+/// Cached lookups of the key are valid until they expire, so a revoked key can
+/// still be used for up to `API_KEY_CACHE_TTL_SECONDS`.
+///
+/// # Parameters
+///
+/// * `id` - The Uuid of the key to revoke
+/// * `database` - An Sqlx database connection pool
+/// ---
+#[tracing::instrument(
+	name = "Revoke an API key."
+	skip(database)
+)]
+pub async fn revoke(
+	id: &Uuid,
+	database: &sqlx::Pool<sqlx::Postgres>,
+) -> Result<u64> {
+	let rows_affected = sqlx::query!(
+		r#"
+			UPDATE api_keys
+			SET revoked_at = $2
+			WHERE id = $1 AND revoked_at IS NULL
+		"#,
+		id,
+		Utc::now(),
+	)
+	.execute(database)
+	.await?
+	.rows_affected();
+
+	Ok(rows_affected)
+}
+
+/// Look up `key`, returning the `ApiKey` if it is known and not revoked
+///
+/// # Parameters
+///
+/// * `key` - The key sent by the caller
+/// * `database` - An Sqlx database connection pool
+/// ---
+#[tracing::instrument(
+	name = "Look up an API key."
+	skip(key, database)
+)]
+pub async fn lookup(
+	key: &str,
+	database: &sqlx::Pool<sqlx::Postgres>,
+) -> Result<Option<ApiKey>> {
+	let api_key = sqlx::query_as!(
+		ApiKey,
+		r#"
+			SELECT id, name
+			FROM api_keys
+			WHERE key_hash = $1 AND revoked_at IS NULL
+		"#,
+		hash_key(key),
+	)
+	.fetch_optional(database)
+	.await?;
+
+	Ok(api_key)
+}
+
+/// Key lookups cached for `ttl`, keyed by the key hash
+///
+/// Unknown and revoked keys are cached as well as active ones, so repeated
+/// requests with a bad key do not reach the database either. At most
+/// `API_KEY_CACHE_MAX_UNKNOWN` of them are cached, lookups of further bad keys
+/// go to the database until cached ones expire.
+pub struct ApiKeyCache {
+	ttl: Duration,
+	entries: Mutex<HashMap<String, (Option<ApiKey>, Instant)>>,
+}
+
+impl Default for ApiKeyCache {
+	fn default() -> Self {
+		Self::new(Duration::from_secs(API_KEY_CACHE_TTL_SECONDS))
+	}
+}
+
+impl ApiKeyCache {
+	/// Create an empty cache holding lookups for `ttl`
+	pub fn new(ttl: Duration) -> Self {
+		Self { ttl, entries: Mutex::new(HashMap::new()) }
+	}
+
+	/// Look up `key`, using a cached lookup if it has not expired
+	pub async fn lookup(
+		&self,
+		key: &str,
+		database: &sqlx::Pool<sqlx::Postgres>,
+	) -> Result<Option<ApiKey>> {
+		let key_hash = hash_key(key);
+		if let Some((api_key, cached_at)) = self.entries().get(&key_hash) {
+			if cached_at.elapsed() < self.ttl {
+				return Ok(api_key.clone());
+			}
+		}
+
+		let api_key = lookup(key, database).await?;
+		let mut entries = self.entries();
+		// Drop expired lookups, so unknown keys do not grow the cache unbounded
+		entries.retain(|_, (_, cached_at)| cached_at.elapsed() < self.ttl);
+		let unknown_count = entries.values().filter(|(api_key, _)| api_key.is_none()).count();
+		if api_key.is_some() || unknown_count < API_KEY_CACHE_MAX_UNKNOWN {
+			entries.insert(key_hash, (api_key.clone(), Instant::now()));
+		}
+
+		Ok(api_key)
+	}
+
+	fn entries(&self) -> std::sync::MutexGuard<'_, HashMap<String, (Option<ApiKey>, Instant)>> {
+		self.entries.lock().expect("API key cache lock poisoned")
+	}
+}
+
+#[cfg(test)]
+pub mod tests {
+	// Bring module functions into test scope
+	use super::*;
+
+	// Override with more flexible error
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>;
+
+	use sqlx::{Pool, Postgres};
+
+	#[test]
+	fn hash_key_is_a_sha256_hex_digest() {
+		let hash = hash_key("secret");
+
+		assert_eq!(hash.len(), 64);
+		assert_eq!(hash, hash_key("secret"));
+		assert_ne!(hash, hash_key("Secret"));
+	}
+
+	#[sqlx::test]
+	async fn issued_key_is_found_until_revoked(pool: Pool<Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let (id, key) = issue("billing", &pool).await?;
+
+		//-- Execute Function (Act)
+		let found = lookup(&key, &pool).await?;
+		let revoked = revoke(&id, &pool).await?;
+		let found_after_revoke = lookup(&key, &pool).await?;
+
+		//-- Checks (Assertions)
+		assert_eq!(found, Some(ApiKey { id, name: "billing".to_string() }));
+		assert_eq!(revoked, 1);
+		assert_eq!(found_after_revoke, None);
+		assert_eq!(lookup("unknown", &pool).await?, None);
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn cache_holds_lookups_until_they_expire(pool: Pool<Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let (id, key) = issue("billing", &pool).await?;
+		let cache = ApiKeyCache::new(Duration::from_secs(60));
+		let expired_cache = ApiKeyCache::new(Duration::ZERO);
+		cache.lookup(&key, &pool).await?;
+		expired_cache.lookup(&key, &pool).await?;
+
+		//-- Execute Function (Act)
+		revoke(&id, &pool).await?;
+
+		//-- Checks (Assertions)
+		// The cached lookup is used, so the revoke is not seen yet
+		assert!(cache.lookup(&key, &pool).await?.is_some());
+		assert!(expired_cache.lookup(&key, &pool).await?.is_none());
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn cache_holds_a_limited_number_of_unknown_keys(pool: Pool<Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let cache = ApiKeyCache::new(Duration::from_secs(60));
+		let (_, key) = issue("billing", &pool).await?;
+
+		//-- Execute Function (Act)
+		for count in 0..=API_KEY_CACHE_MAX_UNKNOWN {
+			cache.lookup(&format!("unknown {count}"), &pool).await?;
+		}
+		cache.lookup(&key, &pool).await?;
+
+		//-- Checks (Assertions)
+		let entries = cache.entries();
+		let unknown_count = entries.values().filter(|(api_key, _)| api_key.is_none()).count();
+		assert_eq!(unknown_count, API_KEY_CACHE_MAX_UNKNOWN);
+		// Active keys are still cached once the unknown limit is reached
+		assert!(entries.contains_key(&hash_key(&key)));
+
+		Ok(())
+	}
+}
@@ -0,0 +1,168 @@
+// -- ./src/services/audit.rs
+
+//! A service for recording and reading the audit log
+//!
+//! Services record an `AuditEntry` in the same transaction as the mutation it
+//! describes, so the log can not disagree with the data. The `diff` is a JSON
+//! object keyed by the changed field, holding its `old` and `new` values.
+
+use crate::prelude::*;
+
+use chrono::prelude::*;
+use tracing::debug;
+use uuid::Uuid;
+
+/// The kind of mutation an audit entry records
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AuditAction {
+	Insert,
+	Update,
+	Delete,
+}
+
+impl AuditAction {
+	/// The action as stored in the `audit_log` table
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::Insert => "insert",
+			Self::Update => "update",
+			Self::Delete => "delete",
+		}
+	}
+}
+
+/// An audit log entry to record
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuditEntry {
+	/// The kind of entity mutated, i.e. `thing`
+	pub entity: &'static str,
+	pub entity_id: Uuid,
+	pub action: AuditAction,
+	/// The id of the user making the mutation
+	pub actor: Uuid,
+	pub diff: serde_json::Value,
+}
+
+/// A recorded `audit_log` table row
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuditRecord {
+	pub id: Uuid,
+	pub entity: String,
+	pub entity_id: Uuid,
+	pub action: String,
+	pub actor: Uuid,
+	pub diff: serde_json::Value,
+	pub created_at: DateTime<Utc>,
+}
+
+/// Add `field` to the `diff` object if its `old` and `new` values differ
+pub fn diff_field(
+	diff: &mut serde_json::Map<String, serde_json::Value>,
+	field: &str,
+	old: serde_json::Value,
+	new: serde_json::Value,
+) {
+	if old != new {
+		diff.insert(field.to_string(), serde_json::json!({ "old": old, "new": new }));
+	}
+}
+
+/// Record `entry` in the audit log as part of `transaction`
+///
+/// # Parameters
+///
+/// * `entry` - The audit entry to record
+/// * `transaction` - The transaction of the mutation the entry describes
+/// ---
+#[tracing::instrument(
+	name = "Record an audit log entry."
+	skip(transaction)
+)]
+pub async fn record(
+	entry: &AuditEntry,
+	transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> Result<()> {
+	// The diff is bound as text and cast, as sqlx is built without json support
+	sqlx::query!(
+		r#"
+			INSERT INTO audit_log (id, entity, entity_id, action, actor, diff)
+			VALUES ($1, $2, $3, $4, $5, $6::text::jsonb)
+		"#,
+		Uuid::now_v7(),
+		entry.entity,
+		entry.entity_id,
+		entry.action.as_str(),
+		entry.actor,
+		entry.diff.to_string(),
+	)
+	.execute(&mut **transaction)
+	.await?;
+	debug!("Audit log entry recorded: {entry:#?}");
+
+	Ok(())
+}
+
+/// Get the audit log of the `entity` with `entity_id`, oldest first
+///
+/// # Parameters
+///
+/// * `entity` - The kind of entity, i.e. `thing`
+/// * `entity_id` - The Uuid of the entity
+/// * `database` - An Sqlx database connection pool
+/// ---
+#[tracing::instrument(
+	name = "Get the audit log of an entity."
+	skip(database)
+)]
+pub async fn index_by_entity(
+	entity: &str,
+	entity_id: &Uuid,
+	database: &sqlx::Pool<sqlx::Postgres>,
+) -> Result<Vec<AuditRecord>> {
+	let records = sqlx::query!(
+		r#"
+			SELECT id, entity, entity_id, action, actor, diff::text AS "diff!", created_at
+			FROM audit_log
+			WHERE entity = $1 AND entity_id = $2
+			ORDER BY id
+		"#,
+		entity,
+		entity_id,
+	)
+	.fetch_all(database)
+	.await?;
+
+	records
+		.into_iter()
+		.map(|record| {
+			Ok(AuditRecord {
+				id: record.id,
+				entity: record.entity,
+				entity_id: record.entity_id,
+				action: record.action,
+				actor: record.actor,
+				diff: serde_json::from_str(&record.diff)?,
+				created_at: record.created_at,
+			})
+		})
+		.collect()
+}
+
+#[cfg(test)]
+pub mod tests {
+	// Bring module functions into test scope
+	use super::*;
+
+	#[test]
+	fn diff_field_only_adds_changed_fields() {
+		let mut diff = serde_json::Map::new();
+
+		diff_field(&mut diff, "name", "Old".into(), "New".into());
+		diff_field(&mut diff, "status", "active".into(), "active".into());
+
+		assert_eq!(
+			serde_json::Value::Object(diff),
+			serde_json::json!({ "name": { "old": "Old", "new": "New" } })
+		);
+	}
+}
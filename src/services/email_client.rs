@@ -0,0 +1,210 @@
+// -- ./src/services/email_client.rs
+
+//! A client for sending emails through the email provider REST API
+//!
+//! The client is built from the `email_client` configuration settings and wraps
+//! a `reqwest::Client`, so the connection pool is reused between emails.
+
+use crate::configuration::EmailClientSettings;
+use crate::prelude::*;
+
+use reqwest::header::CONTENT_TYPE;
+use secrecy::{ExposeSecret, Secret};
+use std::time::Duration;
+
+/// Send emails from the configured sender through the email provider
+#[derive(Debug, Clone)]
+pub struct EmailClient {
+	http_client: reqwest::Client,
+	base_url: String,
+	sender_email: String,
+	authorisation_token: Secret<String>,
+}
+
+/// Body of the email provider send email request
+#[derive(serde::Serialize)]
+struct SendEmailRequest<'a> {
+	from: &'a str,
+	to: &'a str,
+	subject: &'a str,
+	html_body: &'a str,
+	text_body: &'a str,
+}
+
+impl EmailClient {
+	/// Build an `EmailClient` from the email client settings, requests to the
+	/// provider time out after `timeout_milliseconds`.
+	pub fn new(settings: &EmailClientSettings) -> Self {
+		let http_client = reqwest::Client::builder()
+			.timeout(Duration::from_millis(settings.timeout_milliseconds))
+			.build()
+			.expect("Failed to build the email http client.");
+
+		Self {
+			http_client,
+			base_url: settings.base_url.clone(),
+			sender_email: settings.sender_email.clone(),
+			authorisation_token: settings.authorisation_token.clone(),
+		}
+	}
+
+	/// Send an email to `recipient`, returning an error if the provider can not
+	/// be reached, times out or responds with an error status.
+	///
+	/// # Parameters
+	///
+	/// * `recipient` - Email address to send the email to
+	/// * `subject` - Email subject line
+	/// * `html_body` - HTML version of the email body
+	/// * `text_body` - Plain text version of the email body
+	/// ---
+	#[tracing::instrument(
+		name = "Send an email."
+		skip(self, html_body, text_body)
+	)]
+	pub async fn send_email(
+		&self,
+		recipient: &str,
+		subject: &str,
+		html_body: &str,
+		text_body: &str,
+	) -> Result<()> {
+		let url = format!("{}/email", self.base_url.trim_end_matches('/'));
+		let request_body = SendEmailRequest {
+			from: &self.sender_email,
+			to: recipient,
+			subject,
+			html_body,
+			text_body,
+		};
+
+		self.http_client
+			.post(&url)
+			.bearer_auth(self.authorisation_token.expose_secret())
+			.header(CONTENT_TYPE, "application/json")
+			.body(serde_json::to_vec(&request_body)?)
+			.send()
+			.await
+			.and_then(|response| response.error_for_status())
+			.map_err(Error::EmailClient)?;
+
+		Ok(())
+	}
+}
+
+//-- Unit Tests
+#[cfg(test)]
+pub mod tests {
+	// Bring module functions into test scope
+	use super::*;
+
+	// Override with more flexible error
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>;
+
+	use fake::faker::internet::en::SafeEmail;
+	use fake::faker::lorem::en::{Paragraph, Sentence};
+	use fake::Fake;
+	use wiremock::matchers::{any, header, header_exists, method, path};
+	use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+	/// Match a send email request body with every field set
+	struct SendEmailBodyMatcher;
+
+	impl wiremock::Match for SendEmailBodyMatcher {
+		fn matches(&self, request: &Request) -> bool {
+			match serde_json::from_slice::<serde_json::Value>(&request.body) {
+				Ok(body) => ["from", "to", "subject", "html_body", "text_body"]
+					.iter()
+					.all(|field| body.get(field).is_some_and(|value| value.is_string())),
+				Err(_) => false,
+			}
+		}
+	}
+
+	/// Build an `EmailClient` sending to the mock server `base_url`
+	fn email_client(base_url: String) -> EmailClient {
+		EmailClient::new(&EmailClientSettings {
+			base_url,
+			sender_email: SafeEmail().fake(),
+			authorisation_token: Secret::new(Sentence(1..2).fake()),
+			timeout_milliseconds: 200,
+		})
+	}
+
+	/// Send an email with random content to a random recipient
+	async fn send_random_email(email_client: &EmailClient) -> crate::prelude::Result<()> {
+		let recipient: String = SafeEmail().fake();
+		let subject: String = Sentence(1..2).fake();
+		let content: String = Paragraph(1..10).fake();
+		email_client.send_email(&recipient, &subject, &content, &content).await
+	}
+
+	#[tokio::test]
+	async fn send_email_sends_the_expected_request() -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let mock_server = MockServer::start().await;
+		let email_client = email_client(mock_server.uri());
+		Mock::given(header_exists("Authorization"))
+			.and(header("Content-Type", "application/json"))
+			.and(path("/email"))
+			.and(method("POST"))
+			.and(SendEmailBodyMatcher)
+			.respond_with(ResponseTemplate::new(200))
+			.expect(1)
+			.mount(&mock_server)
+			.await;
+
+		//-- Execute Function (Act)
+		let result = send_random_email(&email_client).await;
+
+		//-- Checks (Assertions)
+		// The mock server checks the request expectation when dropped
+		assert!(result.is_ok());
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn send_email_fails_if_the_server_returns_500() -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let mock_server = MockServer::start().await;
+		let email_client = email_client(mock_server.uri());
+		Mock::given(any())
+			.respond_with(ResponseTemplate::new(500))
+			.expect(1)
+			.mount(&mock_server)
+			.await;
+
+		//-- Execute Function (Act)
+		let result = send_random_email(&email_client).await;
+
+		//-- Checks (Assertions)
+		assert!(matches!(result, Err(crate::error::Error::EmailClient(_))));
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn send_email_times_out_if_the_server_takes_too_long() -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let mock_server = MockServer::start().await;
+		let email_client = email_client(mock_server.uri());
+		Mock::given(any())
+			.respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(10)))
+			.expect(1)
+			.mount(&mock_server)
+			.await;
+
+		//-- Execute Function (Act)
+		let result = send_random_email(&email_client).await;
+
+		//-- Checks (Assertions)
+		match result {
+			Err(crate::error::Error::EmailClient(error)) => assert!(error.is_timeout()),
+			other => panic!("Expected a timeout error, got {other:?}"),
+		}
+
+		Ok(())
+	}
+}
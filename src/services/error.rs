@@ -2,20 +2,50 @@
 //! 
 //! This is passed through to the main error crate
 
-#[derive(thiserror::Error, Debug)]
+/// Services module errors
+#[derive(thiserror::Error, Debug, PartialEq)]
 pub enum Error {
+	#[error("There is no Thing associated with the provided id.")]
+    ThingUnknownId,
 
-	#[error("Database error: {0}")]
-    Database(#[from] sqlx::Error),
-	
-	/// For starter, to remove as code matures.
-	#[error("Generic error: {0}")]
-	Generic(String),
+	#[error("There is no Thing associated with the provided name.")]
+    ThingUnknownName,
 
-	/// For starter, to remove as code matures.
-	#[error("Static error: {0}")]
-	Static(&'static str),
+	#[error("There is no Thing associated with the provided slug.")]
+    ThingUnknownSlug,
 
-	#[error(transparent)]
-	IO(#[from] std::io::Error),
-}
\ No newline at end of file
+	#[error("There is no Note associated with the provided id.")]
+    NoteUnknownId,
+
+	#[error("There is no User associated with the provided id.")]
+	UserUnknownId,
+
+	#[error("The verification token is invalid or has already been used.")]
+	VerificationTokenInvalid,
+
+	#[error("The verification token has expired.")]
+	VerificationTokenExpired,
+
+	#[error("The API key is invalid or has been revoked.")]
+	ApiKeyInvalid,
+
+	#[error("A request with the same idempotency key is still in progress.")]
+	IdempotencyKeyInProgress,
+}
+
+impl Error {
+	/// The name of the error variant, used as the error `code` in responses
+	pub fn code(&self) -> &'static str {
+		match self {
+			Self::ThingUnknownId => "ThingUnknownId",
+			Self::ThingUnknownName => "ThingUnknownName",
+			Self::ThingUnknownSlug => "ThingUnknownSlug",
+			Self::NoteUnknownId => "NoteUnknownId",
+			Self::UserUnknownId => "UserUnknownId",
+			Self::VerificationTokenInvalid => "VerificationTokenInvalid",
+			Self::VerificationTokenExpired => "VerificationTokenExpired",
+			Self::ApiKeyInvalid => "ApiKeyInvalid",
+			Self::IdempotencyKeyInProgress => "IdempotencyKeyInProgress",
+		}
+	}
+}
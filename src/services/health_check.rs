@@ -0,0 +1,41 @@
+// -- ./src/services/health_check.rs
+
+//! A service for checking the database is reachable
+
+use crate::prelude::*;
+
+/// Run a lightweight `SELECT 1` against the database, returning an error if
+/// the database cannot be reached.
+///
+/// # Parameters
+///
+/// * `database` - An Sqlx database connection pool
+/// ---
+#[tracing::instrument(
+	name = "Check the database is reachable."
+	skip(database)
+)]
+pub async fn ping_database(database: &sqlx::Pool<sqlx::Postgres>) -> Result<()> {
+	sqlx::query("SELECT 1").execute(database).await?;
+
+	Ok(())
+}
+
+/// Count the migrations successfully applied to the database, as recorded by
+/// sqlx in the `_sqlx_migrations` table
+///
+/// # Parameters
+///
+/// * `database` - An Sqlx database connection pool
+/// ---
+#[tracing::instrument(
+	name = "Count the applied database migrations."
+	skip(database)
+)]
+pub async fn migrations_applied(database: &sqlx::Pool<sqlx::Postgres>) -> Result<i64> {
+	let count = sqlx::query_scalar("SELECT COUNT(*) FROM _sqlx_migrations WHERE success")
+		.fetch_one(database)
+		.await?;
+
+	Ok(count)
+}
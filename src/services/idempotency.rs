@@ -0,0 +1,225 @@
+// -- ./src/services/idempotency.rs
+
+//! A service for storing the responses of idempotent requests
+//!
+//! A client retrying a request sends the same `Idempotency-Key` header, and
+//! gets the response saved for the key rather than the request being processed
+//! again. Saved responses expire after the store `ttl`, after which the key can
+//! be used for a new request.
+//!
+//! Keys are scoped to the client that sent them, so a client can only replay
+//! its own responses. A key is claimed before the request is processed, so of
+//! two concurrent requests with the same key only one is processed, and its
+//! response is saved in the same transaction as the work it describes.
+
+use crate::prelude::*;
+
+use chrono::prelude::Utc;
+use sqlx::PgPool;
+use std::time::Duration;
+use tracing::debug;
+
+/// A response saved against an idempotency key
+#[derive(Clone, Debug, PartialEq)]
+pub struct SavedResponse {
+	pub status_code: u16,
+	/// The json response body
+	pub body: String,
+}
+
+/// The outcome of claiming an idempotency key
+#[derive(Clone, Debug, PartialEq)]
+pub enum Claim {
+	/// The key was free, and is now held for this request
+	Claimed,
+	/// The key has a saved response to replay
+	Saved(SavedResponse),
+	/// The key is held by a request that has not finished
+	InProgress,
+}
+
+/// Saved responses in the `idempotency` table, valid for `ttl`
+pub struct IdempotencyStore {
+	ttl: Duration,
+	database: PgPool,
+}
+
+impl IdempotencyStore {
+	/// Create a store in the `database` with responses valid for `ttl`
+	pub fn new(ttl: Duration, database: PgPool) -> Self {
+		Self { ttl, database }
+	}
+
+	/// The earliest time a saved response is still valid from
+	fn valid_after(&self) -> chrono::DateTime<Utc> {
+		let ttl = chrono::Duration::from_std(self.ttl).unwrap_or(chrono::Duration::MAX);
+		Utc::now().checked_sub_signed(ttl).unwrap_or(chrono::DateTime::<Utc>::MIN_UTC)
+	}
+
+	/// Begin a transaction on the store database, to `save` a response in
+	pub async fn begin(&self) -> Result<sqlx::Transaction<'static, sqlx::Postgres>> {
+		Ok(self.database.begin().await?)
+	}
+
+	/// Claim `key` in the client `scope`, removing the expired keys first
+	///
+	/// A claimed key must be finished with `save`, or `release` if the request
+	/// fails, otherwise it is held until it expires.
+	#[tracing::instrument(
+		name = "Claim an idempotency key."
+		skip(self)
+	)]
+	pub async fn claim(&self, scope: &str, key: &str) -> Result<Claim> {
+		sqlx::query!(
+			r#"
+				DELETE FROM idempotency
+				WHERE created_at <= $1
+			"#,
+			self.valid_after(),
+		)
+		.execute(&self.database)
+		.await?;
+
+		let claimed = sqlx::query!(
+			r#"
+				INSERT INTO idempotency (scope, key, created_at)
+				VALUES ($1, $2, $3)
+				ON CONFLICT DO NOTHING
+			"#,
+			scope,
+			key,
+			Utc::now(),
+		)
+		.execute(&self.database)
+		.await?;
+		if claimed.rows_affected() == 1 {
+			debug!("Idempotency key claimed: {key}");
+			return Ok(Claim::Claimed);
+		}
+
+		let record = sqlx::query!(
+			r#"
+				SELECT status_code, body
+				FROM idempotency
+				WHERE scope = $1 AND key = $2
+			"#,
+			scope,
+			key,
+		)
+		.fetch_optional(&self.database)
+		.await?;
+
+		Ok(match record {
+			Some(record) => match (record.status_code, record.body) {
+				(Some(status_code), Some(body)) => {
+					Claim::Saved(SavedResponse { status_code: status_code as u16, body })
+				}
+				_ => Claim::InProgress,
+			},
+			// Released since the insert, so the client can retry
+			None => Claim::InProgress,
+		})
+	}
+
+	/// Save `response` for the claimed `key` in the client `scope`, as part
+	/// of `transaction`
+	#[tracing::instrument(
+		name = "Save an idempotent response."
+		skip(self, response, transaction)
+	)]
+	pub async fn save(
+		&self,
+		scope: &str,
+		key: &str,
+		response: &SavedResponse,
+		transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+	) -> Result<()> {
+		sqlx::query!(
+			r#"
+				UPDATE idempotency
+				SET status_code = $3, body = $4
+				WHERE scope = $1 AND key = $2
+			"#,
+			scope,
+			key,
+			response.status_code as i16,
+			response.body,
+		)
+		.execute(&mut **transaction)
+		.await?;
+		debug!("Idempotent response saved for key: {key}");
+
+		Ok(())
+	}
+
+	/// Release the claimed `key` in the client `scope` without a response, so
+	/// a failed request can be retried with the same key
+	#[tracing::instrument(
+		name = "Release an idempotency key."
+		skip(self)
+	)]
+	pub async fn release(&self, scope: &str, key: &str) -> Result<()> {
+		sqlx::query!(
+			r#"
+				DELETE FROM idempotency
+				WHERE scope = $1 AND key = $2 AND body IS NULL
+			"#,
+			scope,
+			key,
+		)
+		.execute(&self.database)
+		.await?;
+		debug!("Idempotency key released: {key}");
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+pub mod tests {
+	// Bring module functions into test scope
+	use super::*;
+
+	// Override with more flexible error
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>;
+
+	#[sqlx::test]
+	async fn saved_responses_are_replayed_until_they_expire(pool: PgPool) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let store = IdempotencyStore::new(Duration::from_secs(60), pool.clone());
+		let expired_store = IdempotencyStore::new(Duration::ZERO, pool);
+		let response = SavedResponse { status_code: 200, body: "{}".to_string() };
+
+		//-- Execute Function (Act)
+		assert_eq!(store.claim("ip:127.0.0.1", "retry").await?, Claim::Claimed);
+		let in_progress = store.claim("ip:127.0.0.1", "retry").await?;
+		let mut transaction = store.begin().await?;
+		store.save("ip:127.0.0.1", "retry", &response, &mut transaction).await?;
+		transaction.commit().await?;
+
+		//-- Checks (Assertions)
+		assert_eq!(in_progress, Claim::InProgress);
+		assert_eq!(store.claim("ip:127.0.0.1", "retry").await?, Claim::Saved(response));
+		// Keys are scoped to the client
+		assert_eq!(store.claim("ip:10.0.0.1", "retry").await?, Claim::Claimed);
+		assert_eq!(expired_store.claim("ip:127.0.0.1", "retry").await?, Claim::Claimed);
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn a_released_key_can_be_claimed_again(pool: PgPool) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let store = IdempotencyStore::new(Duration::from_secs(60), pool);
+		store.claim("ip:127.0.0.1", "retry").await?;
+
+		//-- Execute Function (Act)
+		store.release("ip:127.0.0.1", "retry").await?;
+
+		//-- Checks (Assertions)
+		assert_eq!(store.claim("ip:127.0.0.1", "retry").await?, Claim::Claimed);
+
+		Ok(())
+	}
+}
@@ -2,8 +2,26 @@
 //! 
 //! Services interface with the database for creating, reading, updating and
 //! deleting database rows.
+//!
+//! # Conventions
+//!
+//! Index (list) services take `limit` and `offset` as `i64` values rather than
+//! references, since they are `Copy`. Handlers resolve the optional URL
+//! parameters to values and pass them straight through, i.e.
+//! `things::index(limit, offset, &pool)`.
 
+pub mod api_keys;
+pub mod audit;
+pub mod email_client;
 pub mod error;
+pub mod health_check;
+pub mod idempotency;
+pub mod notes;
+pub mod thing_repository;
 pub mod things;
+pub mod users;
+pub mod verification;
+
+pub use error::Error;
 
 // pub use things::*;
@@ -0,0 +1,226 @@
+// -- ./src/services/notes.rs
+
+//! Services for creating, listing and deleting the notes on a Thing
+//!
+//! Notes are scoped to a parent Thing, so each service first checks the Thing
+//! exists, returning `services::Error::ThingUnknownId` if it does not (or has
+//! been soft deleted).
+//! ---
+
+use crate::{
+	domain::{Note, NoteRow},
+	prelude::*,
+	services::{self, things},
+};
+use tracing::debug;
+use uuid::Uuid;
+
+/// Insert a `Note` into the database, returning the `Note` created.
+///
+/// # Parameters
+///
+/// * `note` - A Note instance, with the `thing_id` of its parent Thing
+/// * `database` - An Sqlx database connection pool
+/// ---
+#[tracing::instrument(
+	name = "Insert a new Note into the database."
+	skip(note, database)
+)]
+pub async fn insert(
+	note: &Note,
+	database: &sqlx::Pool<sqlx::Postgres>,
+) -> Result<Note> {
+	things::get_by_id(&note.thing_id, database).await?;
+
+	let database_record = sqlx::query_as!(
+		NoteRow,
+		r#"
+			INSERT INTO thing_notes (id, thing_id, body, created_at, updated_at)
+			VALUES ($1, $2, $3, $4, $5)
+			RETURNING *
+		"#,
+		note.id,
+		note.thing_id,
+		note.body.as_ref(),
+		note.created_at,
+		note.updated_at,
+	)
+	.fetch_one(database)
+	.await?;
+	debug!("Record inserted into database: {database_record:#?}");
+
+	Note::try_from(database_record)
+}
+
+/// Get the notes on the Thing with `thing_id`, oldest first, returning a vector
+/// of Notes.
+///
+/// # Parameters
+///
+/// * `thing_id` - The Uuid of the parent Thing
+/// * `database` - An sqlx database pool that the notes will be searched in.
+/// ---
+#[tracing::instrument(
+	name = "Index of Notes on a Thing."
+	skip(database)
+)]
+pub async fn index_by_thing(
+	thing_id: &Uuid,
+	database: &sqlx::Pool<sqlx::Postgres>,
+) -> Result<Vec<Note>> {
+	things::get_by_id(thing_id, database).await?;
+
+	let records = sqlx::query_as!(
+		NoteRow,
+		r#"
+			SELECT *
+			FROM thing_notes
+			WHERE thing_id = $1
+			ORDER BY created_at, id
+		"#,
+		thing_id
+	)
+	.fetch_all(database)
+	.await?;
+	debug!("Database records returned from database: {records:#?}");
+
+	records
+		.into_iter()
+		.map(Note::try_from)
+		.collect::<Result<Vec<Note>>>()
+}
+
+/// Delete the note with `id` on the Thing with `thing_id`, returning
+/// `services::Error::NoteUnknownId` if the Thing has no note with the id.
+///
+/// # Parameters
+///
+/// * `thing_id` - The Uuid of the parent Thing
+/// * `id` - The Uuid of the Note database row you want to delete
+/// * `database` - An Sqlx database connection pool
+/// ---
+#[tracing::instrument(
+	name = "Delete a Note on a Thing in the database."
+	skip(database)
+)]
+pub async fn delete_by_id(
+	thing_id: &Uuid,
+	id: &Uuid,
+	database: &sqlx::Pool<sqlx::Postgres>,
+) -> Result<()> {
+	things::get_by_id(thing_id, database).await?;
+
+	let record = sqlx::query!(
+		r#"
+			DELETE
+			FROM thing_notes
+			WHERE id = $1 AND thing_id = $2
+		"#,
+		id,
+		thing_id
+	)
+	.execute(database)
+	.await?;
+	debug!("Record deleted form database: {record:#?}");
+
+	if record.rows_affected() == 0 {
+		return Err(services::Error::NoteUnknownId.into());
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+pub mod tests {
+	// Bring file/module functions into unit test scope
+	use super::*;
+
+	// Override with more flexible error
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>;
+
+	use crate::domain::NoteBody;
+	use crate::services::things::tests::create_random_test_thing;
+	use chrono::{Duration, Utc};
+	use fake::faker::lorem::en::Sentence;
+	use fake::Fake;
+	use sqlx::{Pool, Postgres};
+
+	// Test creating notes on a Thing and listing them oldest first
+	#[sqlx::test]
+	async fn insert_and_index_notes_in_order(pool: Pool<Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let thing = things::insert(&create_random_test_thing().await?, &pool).await?;
+		let other_thing = things::insert(&create_random_test_thing().await?, &pool).await?;
+		let now = Utc::now();
+		// Insert the notes out of order
+		let mut test_vec: Vec<Note> = Vec::new();
+		for hours in [2, 0, 1] {
+			let mut note = Note::new(thing.id, NoteBody::parse(Sentence(3..7).fake::<String>())?);
+			note.created_at = now - Duration::hours(hours);
+			test_vec.push(insert(&note, &pool).await?);
+		}
+		insert(
+			&Note::new(other_thing.id, NoteBody::parse(Sentence(3..7).fake::<String>())?),
+			&pool,
+		)
+		.await?;
+
+		//-- Execute Function (Act)
+		let records = index_by_thing(&thing.id, &pool).await?;
+
+		//-- Checks (Assertions)
+		// Oldest first, and only the notes on the Thing
+		test_vec.sort_by_key(|note| note.created_at);
+		let record_ids: Vec<Uuid> = records.iter().map(|note| note.id).collect();
+		let test_ids: Vec<Uuid> = test_vec.iter().map(|note| note.id).collect();
+		assert_eq!(record_ids, test_ids);
+
+		Ok(())
+	}
+
+	// Test notes can not be added to a Thing that does not exist
+	#[sqlx::test]
+	async fn notes_on_unknown_thing_error(pool: Pool<Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let note = Note::new(Uuid::now_v7(), NoteBody::parse(Sentence(3..7).fake::<String>())?);
+
+		//-- Execute Function (Act)
+		let insert_error = insert(&note, &pool).await.unwrap_err();
+		let index_error = index_by_thing(&note.thing_id, &pool).await.unwrap_err();
+
+		//-- Checks (Assertions)
+		assert!(matches!(
+			insert_error,
+			crate::error::Error::Services(services::Error::ThingUnknownId)
+		));
+		assert!(matches!(
+			index_error,
+			crate::error::Error::Services(services::Error::ThingUnknownId)
+		));
+
+		Ok(())
+	}
+
+	// Test deleting a note
+	#[sqlx::test]
+	async fn delete_note(pool: Pool<Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let thing = things::insert(&create_random_test_thing().await?, &pool).await?;
+		let note = Note::new(thing.id, NoteBody::parse(Sentence(3..7).fake::<String>())?);
+		let note = insert(&note, &pool).await?;
+
+		//-- Execute Function (Act)
+		delete_by_id(&thing.id, &note.id, &pool).await?;
+		let error = delete_by_id(&thing.id, &note.id, &pool).await.unwrap_err();
+
+		//-- Checks (Assertions)
+		assert!(index_by_thing(&thing.id, &pool).await?.is_empty());
+		assert!(matches!(
+			error,
+			crate::error::Error::Services(services::Error::NoteUnknownId)
+		));
+
+		Ok(())
+	}
+}
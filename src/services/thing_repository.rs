@@ -0,0 +1,177 @@
+// -- ./src/services/thing_repository.rs
+
+//! A repository abstraction over the Thing CRUD services
+//!
+//! Handlers depend on `Data<dyn ThingRepository>` rather than a Postgres pool,
+//! so handler unit tests can inject an `InMemoryThingRepository` and run
+//! without a database. `PgThingRepository` wraps the `services::things`
+//! functions for the application, the in memory repository is only built for
+//! tests.
+
+use crate::{domain::Thing, prelude::*, services::things};
+
+use futures::future::{BoxFuture, FutureExt};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+#[cfg(test)]
+pub use in_memory::InMemoryThingRepository;
+
+/// Create, read, update and delete Things
+///
+/// The methods mirror the `services::things` functions of the same name, and
+/// return boxed futures so the trait can be used as `dyn ThingRepository`.
+pub trait ThingRepository: Send + Sync {
+	/// Insert a `Thing`, returning the `Thing` created or
+	/// `Error::ThingNameTaken` if another Thing already has the name
+	fn insert<'a>(&'a self, thing: &'a Thing) -> BoxFuture<'a, Result<Thing>>;
+
+	/// Get the `Thing` with `id` that has not been soft deleted, or
+	/// `services::Error::ThingUnknownId`
+	fn get_by_id<'a>(&'a self, id: &'a Uuid) -> BoxFuture<'a, Result<Thing>>;
+
+	/// Update a `Thing` at `thing.version`, returning the updated `Thing`,
+	/// `Error::StaleUpdate` or `services::Error::ThingUnknownId`
+	fn update<'a>(&'a self, thing: &'a Thing) -> BoxFuture<'a, Result<Thing>>;
+
+	/// Delete the `Thing` with `id`, returning the number of Things deleted
+	fn delete_by_id<'a>(&'a self, id: &'a Uuid) -> BoxFuture<'a, Result<u64>>;
+}
+
+/// A `ThingRepository` backed by the Postgres `things` table
+pub struct PgThingRepository {
+	database: PgPool,
+}
+
+impl PgThingRepository {
+	/// Create a repository using the `database` connection pool
+	pub fn new(database: PgPool) -> Self {
+		Self { database }
+	}
+}
+
+impl ThingRepository for PgThingRepository {
+	fn insert<'a>(&'a self, thing: &'a Thing) -> BoxFuture<'a, Result<Thing>> {
+		things::insert(thing, &self.database).boxed()
+	}
+
+	fn get_by_id<'a>(&'a self, id: &'a Uuid) -> BoxFuture<'a, Result<Thing>> {
+		things::get_by_id(id, &self.database).boxed()
+	}
+
+	fn update<'a>(&'a self, thing: &'a Thing) -> BoxFuture<'a, Result<Thing>> {
+		things::update(thing, &self.database).boxed()
+	}
+
+	fn delete_by_id<'a>(&'a self, id: &'a Uuid) -> BoxFuture<'a, Result<u64>> {
+		things::delete_by_id(id, &self.database).boxed()
+	}
+}
+
+/// The test only in memory `ThingRepository`
+#[cfg(test)]
+mod in_memory {
+	use super::ThingRepository;
+	use crate::{
+		domain::{timestamp, SlugName, Thing},
+		prelude::*,
+		services,
+	};
+
+	use chrono::prelude::Utc;
+	use futures::future::{BoxFuture, FutureExt};
+	use std::sync::Mutex;
+	use uuid::Uuid;
+
+	/// A `ThingRepository` holding Things in memory, for tests that do not need a
+	/// database
+	///
+	/// It follows the Postgres repository where tests rely on it: names are
+	/// unique ignoring case, updates increment the version, and slugs come from
+	/// the name.
+	/// Taken slugs are not deduplicated.
+	#[derive(Default)]
+	pub struct InMemoryThingRepository {
+		things: Mutex<Vec<Thing>>,
+	}
+
+	impl InMemoryThingRepository {
+		/// Create a repository holding `things`
+		pub fn with_things(things: Vec<Thing>) -> Self {
+			Self { things: Mutex::new(things) }
+		}
+
+		/// A copy of the Things in the repository
+		pub fn things(&self) -> Vec<Thing> {
+			self.things.lock().expect("Thing repository lock poisoned").clone()
+		}
+	}
+
+	impl ThingRepository for InMemoryThingRepository {
+		fn insert<'a>(&'a self, thing: &'a Thing) -> BoxFuture<'a, Result<Thing>> {
+			async move {
+				let mut things = self.things.lock().expect("Thing repository lock poisoned");
+				if things.iter().any(|existing| existing.name.canonical() == thing.name.canonical()) {
+					return Err(Error::ThingNameTaken { name: thing.name.as_ref().to_string() });
+				}
+
+				let mut new_thing = thing.clone();
+				new_thing.slug = SlugName::try_from(&thing.name).ok();
+				things.push(new_thing.clone());
+
+				Ok(new_thing)
+			}
+			.boxed()
+		}
+
+		fn get_by_id<'a>(&'a self, id: &'a Uuid) -> BoxFuture<'a, Result<Thing>> {
+			async move {
+				self.things
+					.lock()
+					.expect("Thing repository lock poisoned")
+					.iter()
+					.find(|thing| thing.id == *id && thing.deleted_at.is_none())
+					.cloned()
+					.ok_or_else(|| services::Error::ThingUnknownId.into())
+			}
+			.boxed()
+		}
+
+		fn update<'a>(&'a self, thing: &'a Thing) -> BoxFuture<'a, Result<Thing>> {
+			async move {
+				let mut things = self.things.lock().expect("Thing repository lock poisoned");
+				if things.iter().any(|existing| existing.name.canonical() == thing.name.canonical() && existing.id != thing.id) {
+					return Err(Error::ThingNameTaken { name: thing.name.as_ref().to_string() });
+				}
+
+				let Some(existing) = things.iter_mut().find(|existing| existing.id == thing.id) else {
+					return Err(services::Error::ThingUnknownId.into());
+				};
+				if existing.version != thing.version {
+					return Err(Error::StaleUpdate { id: thing.id, version: thing.version });
+				}
+
+				existing.name = thing.name.clone();
+				existing.description = thing.description.clone();
+				existing.slug = SlugName::try_from(&thing.name).ok();
+				existing.updated_at = timestamp::truncate(Utc::now());
+				existing.updated_by = thing.updated_by;
+				existing.version += 1;
+
+				Ok(existing.clone())
+			}
+			.boxed()
+		}
+
+		fn delete_by_id<'a>(&'a self, id: &'a Uuid) -> BoxFuture<'a, Result<u64>> {
+			async move {
+				let mut things = self.things.lock().expect("Thing repository lock poisoned");
+				let count = things.len();
+				things.retain(|thing| thing.id != *id);
+
+				Ok((count - things.len()) as u64)
+			}
+			.boxed()
+		}
+	}
+}
@@ -0,0 +1,73 @@
+// -- ./src/services/users.rs
+
+//! A service for inserting and reading users in the database
+
+use crate::prelude::*;
+
+use chrono::prelude::Utc;
+use tracing::debug;
+use uuid::Uuid;
+
+/// Insert a user with an unverified `email` into the database, returning the
+/// new user id
+///
+/// # Parameters
+///
+/// * `email` - The email address of the user
+/// * `database` - An Sqlx database connection pool
+/// ---
+#[tracing::instrument(
+	name = "Insert a new User into the database."
+	skip(database)
+)]
+pub async fn insert(
+	email: &str,
+	database: &sqlx::Pool<sqlx::Postgres>,
+) -> Result<Uuid> {
+	let id = sqlx::query_scalar!(
+		r#"
+			INSERT INTO users (id, email, created_at)
+			VALUES ($1, $2, $3)
+			RETURNING id
+		"#,
+		Uuid::now_v7(),
+		email,
+		Utc::now(),
+	)
+	.fetch_one(database)
+	.await?;
+	debug!("User inserted into database: {id}");
+
+	Ok(id)
+}
+
+/// Has the user with `id` verified their email address, returning
+/// `services::Error::UserUnknownId` if there is no user with the id
+///
+/// # Parameters
+///
+/// * `id` - The Uuid of the user
+/// * `database` - An Sqlx database connection pool
+/// ---
+#[tracing::instrument(
+	name = "Check if a User in the database is verified."
+	skip(database)
+)]
+pub async fn is_verified(
+	id: &Uuid,
+	database: &sqlx::Pool<sqlx::Postgres>,
+) -> Result<bool> {
+	let verified_at = sqlx::query_scalar!(
+		r#"
+			SELECT verified_at
+			FROM users
+			WHERE id = $1
+		"#,
+		id
+	)
+	.fetch_optional(database)
+	.await?
+	.ok_or(crate::services::Error::UserUnknownId)?;
+
+	Ok(verified_at.is_some())
+}
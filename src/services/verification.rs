@@ -0,0 +1,291 @@
+// -- ./src/services/verification.rs
+
+//! A service for issuing and checking email verification tokens
+//!
+//! A token is a random string stored against a user with an expiry and emailed
+//! to them in a verification link. Checking a token consumes it and marks the
+//! user verified, so each token can only be used once.
+
+use crate::api;
+use crate::prelude::*;
+use crate::services::{self, email_client::EmailClient};
+
+use chrono::prelude::Utc;
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+use tracing::debug;
+use uuid::Uuid;
+
+/// Length of a generated verification token
+const VERIFICATION_TOKEN_LENGTH: usize = 25;
+
+/// How long a verification token can be used for, in hours
+pub const VERIFICATION_TOKEN_TTL_HOURS: i64 = 24;
+
+/// Generate a random, case sensitive alphanumeric verification token
+fn generate_token() -> String {
+	rand::thread_rng()
+		.sample_iter(&Alphanumeric)
+		.map(char::from)
+		.take(VERIFICATION_TOKEN_LENGTH)
+		.collect()
+}
+
+/// Issue a verification token for the user with `user_id` and email them a
+/// verification link, returning the token or `services::Error::UserUnknownId`
+/// if there is no user with the id
+///
+/// # Parameters
+///
+/// * `user_id` - The Uuid of the user to verify
+/// * `email_client` - The client the verification email is sent with
+/// * `base_url` - The public URL of the API, the link is built from
+/// * `database` - An Sqlx database connection pool
+/// ---
+#[tracing::instrument(
+	name = "Issue an email verification token."
+	skip(email_client, database)
+)]
+pub async fn issue(
+	user_id: &Uuid,
+	email_client: &EmailClient,
+	base_url: &str,
+	database: &sqlx::Pool<sqlx::Postgres>,
+) -> Result<String> {
+	let email = sqlx::query_scalar!(
+		r#"
+			SELECT email
+			FROM users
+			WHERE id = $1
+		"#,
+		user_id
+	)
+	.fetch_optional(database)
+	.await?
+	.ok_or(services::Error::UserUnknownId)?;
+
+	let token = generate_token();
+	sqlx::query!(
+		r#"
+			INSERT INTO verification_tokens (token, user_id, expires_at)
+			VALUES ($1, $2, $3)
+		"#,
+		token,
+		user_id,
+		Utc::now() + chrono::Duration::hours(VERIFICATION_TOKEN_TTL_HOURS),
+	)
+	.execute(database)
+	.await?;
+	debug!("Verification token stored for user: {user_id}");
+
+	let link = format!(
+		"{}{}/verify?token={token}",
+		base_url.trim_end_matches('/'),
+		api::V1_SCOPE
+	);
+	email_client
+		.send_email(
+			&email,
+			"Verify your email address",
+			&format!("Verify your email address by following <a href=\"{link}\">this link</a>."),
+			&format!("Verify your email address by visiting {link}"),
+		)
+		.await?;
+
+	Ok(token)
+}
+
+/// Consume the verification `token` and mark its user verified, returning the
+/// user id. Returns `services::Error::VerificationTokenInvalid` for an unknown or
+/// used token and `services::Error::VerificationTokenExpired` for an expired one.
+///
+/// # Parameters
+///
+/// * `token` - The verification token from the emailed link
+/// * `database` - An Sqlx database connection pool
+/// ---
+#[tracing::instrument(
+	name = "Check an email verification token."
+	skip(token, database)
+)]
+pub async fn verify(
+	token: &str,
+	database: &sqlx::Pool<sqlx::Postgres>,
+) -> Result<Uuid> {
+	let mut transaction = database.begin().await?;
+
+	// Lock the token row, so a token used twice at once is only consumed once
+	let record = sqlx::query!(
+		r#"
+			SELECT user_id, expires_at, consumed_at
+			FROM verification_tokens
+			WHERE token = $1
+			FOR UPDATE
+		"#,
+		token
+	)
+	.fetch_optional(&mut *transaction)
+	.await?
+	.ok_or(services::Error::VerificationTokenInvalid)?;
+
+	if record.consumed_at.is_some() {
+		return Err(services::Error::VerificationTokenInvalid.into());
+	}
+	if record.expires_at < Utc::now() {
+		return Err(services::Error::VerificationTokenExpired.into());
+	}
+
+	sqlx::query!(
+		r#"
+			UPDATE verification_tokens
+			SET consumed_at = now()
+			WHERE token = $1
+		"#,
+		token
+	)
+	.execute(&mut *transaction)
+	.await?;
+	// Keep the first verification time if the user is already verified
+	sqlx::query!(
+		r#"
+			UPDATE users
+			SET verified_at = now()
+			WHERE id = $1 AND verified_at IS NULL
+		"#,
+		record.user_id
+	)
+	.execute(&mut *transaction)
+	.await?;
+
+	transaction.commit().await?;
+	debug!("User verified: {}", record.user_id);
+
+	Ok(record.user_id)
+}
+
+//-- Unit Tests
+#[cfg(test)]
+pub mod tests {
+	// Bring module functions into test scope
+	use super::*;
+
+	// Override with more flexible error
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>;
+
+	use crate::configuration::EmailClientSettings;
+	use crate::services::users;
+	use fake::faker::internet::en::SafeEmail;
+	use fake::Fake;
+	use secrecy::Secret;
+	use sqlx::{Pool, Postgres};
+	use wiremock::matchers::{method, path};
+	use wiremock::{Mock, MockServer, ResponseTemplate};
+
+	/// Insert a user and issue them a token, with the email sent to a mock server
+	async fn issue_test_token(pool: &Pool<Postgres>) -> Result<(Uuid, String)> {
+		let mock_server = MockServer::start().await;
+		Mock::given(path("/email"))
+			.and(method("POST"))
+			.respond_with(ResponseTemplate::new(200))
+			.expect(1)
+			.mount(&mock_server)
+			.await;
+		let email_client = EmailClient::new(&EmailClientSettings {
+			base_url: mock_server.uri(),
+			sender_email: SafeEmail().fake(),
+			authorisation_token: Secret::new("token".to_string()),
+			timeout_milliseconds: 1000,
+		});
+
+		let user_id = users::insert(&SafeEmail().fake::<String>(), pool).await?;
+		let token = issue(&user_id, &email_client, "http://localhost", pool).await?;
+
+		Ok((user_id, token))
+	}
+
+	#[sqlx::test]
+	async fn verify_token_marks_user_verified(pool: Pool<Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let (user_id, token) = issue_test_token(&pool).await?;
+		assert!(!users::is_verified(&user_id, &pool).await?);
+
+		//-- Execute Function (Act)
+		let verified_id = verify(&token, &pool).await?;
+
+		//-- Checks (Assertions)
+		assert_eq!(token.len(), VERIFICATION_TOKEN_LENGTH);
+		assert_eq!(verified_id, user_id);
+		assert!(users::is_verified(&user_id, &pool).await?);
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn verify_expired_token_errors(pool: Pool<Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let (user_id, token) = issue_test_token(&pool).await?;
+		sqlx::query("UPDATE verification_tokens SET expires_at = now() - interval '1 hour'")
+			.execute(&pool)
+			.await?;
+
+		//-- Execute Function (Act)
+		let error = verify(&token, &pool).await.unwrap_err();
+
+		//-- Checks (Assertions)
+		assert!(matches!(
+			error,
+			crate::error::Error::Services(services::Error::VerificationTokenExpired)
+		));
+		assert!(!users::is_verified(&user_id, &pool).await?);
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn verify_reused_token_errors(pool: Pool<Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let (_user_id, token) = issue_test_token(&pool).await?;
+		verify(&token, &pool).await?;
+
+		//-- Execute Function (Act)
+		let reused = verify(&token, &pool).await.unwrap_err();
+		let unknown = verify("not-a-token", &pool).await.unwrap_err();
+
+		//-- Checks (Assertions)
+		assert!(matches!(
+			reused,
+			crate::error::Error::Services(services::Error::VerificationTokenInvalid)
+		));
+		assert!(matches!(
+			unknown,
+			crate::error::Error::Services(services::Error::VerificationTokenInvalid)
+		));
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn issue_for_unknown_user_errors(pool: Pool<Postgres>) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let email_client = EmailClient::new(&EmailClientSettings {
+			base_url: "http://localhost".to_string(),
+			sender_email: SafeEmail().fake(),
+			authorisation_token: Secret::new("token".to_string()),
+			timeout_milliseconds: 1000,
+		});
+
+		//-- Execute Function (Act)
+		let error = issue(&Uuid::now_v7(), &email_client, "http://localhost", &pool)
+			.await
+			.unwrap_err();
+
+		//-- Checks (Assertions)
+		assert!(matches!(
+			error,
+			crate::error::Error::Services(services::Error::UserUnknownId)
+		));
+
+		Ok(())
+	}
+}
@@ -4,8 +4,16 @@
 //! ---
 
 use crate::api;
+use crate::api_key;
 use crate::configuration::*;
+use crate::handlers::{self, fallback, thing_events::ShuttingDown};
 use crate::prelude::*;
+use crate::rate_limit::{self, RateLimiter};
+use crate::request_id::{self, RequestIdRootSpanBuilder};
+use crate::services::api_keys::ApiKeyCache;
+use crate::services::idempotency::IdempotencyStore;
+use crate::services::thing_repository::{PgThingRepository, ThingRepository};
+use crate::services::things::{self, ThingChange};
 
 use actix_web::dev::Server;
 use actix_web::middleware;
@@ -13,15 +21,23 @@ use actix_web::web;
 use actix_web::web::Data;
 use actix_web::App;
 use actix_web::HttpServer;
+use futures::StreamExt;
 use sqlx::postgres::PgPoolOptions;
 use sqlx::PgPool;
+use std::future::Future;
 use std::net::TcpListener;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{broadcast, watch};
 use tracing_actix_web::TracingLogger;
 
-/// Application port and server instance
+/// Application port, server instance, the database pool it serves and the
+/// shutdown signal of its long lived subscriptions
 pub struct Application {
 	port: u16,
 	server: Server,
+	pool: PgPool,
+	shutdown: watch::Sender<bool>,
 }
 
 /// Actix application instance
@@ -45,17 +61,32 @@ impl Application {
 			configuration.application.address, configuration.application.port
 		);
 		let listener = TcpListener::bind(address)?;
-		let port = listener.local_addr()?.port();
-		let server = run(listener, pool)?;
+		let (shutdown, shutting_down) = watch::channel(false);
+		let (server, port) = run(listener, &configuration, pool.clone(), shutting_down)?;
 
 		tracing::info!(
-			"Starting API server at http://{}:{}/api/v1 in {} environment",
+			address = %configuration.application.address,
+			port,
+			environment = %configuration.application.runtime_environment,
+			modules = ?api::module_names(api::V1_MODULES),
+			v2_modules = ?api::module_names(api::V2_MODULES),
+			"Starting API server at http://{}:{}{} in {} environment",
 			configuration.application.address,
 			port,
+			api::V1_SCOPE,
 			configuration.application.runtime_environment
 		);
+		for route in api::v1_routes().into_iter().chain(api::v2_routes()) {
+			tracing::info!(
+				method = route.method,
+				path = %route.path,
+				"Mounted route {} {}",
+				route.method,
+				route.path
+			);
+		}
 
-		Ok(Self { port, server })
+		Ok(Self { port, server, pool, shutdown })
 	}
 
 	/// Return the port used in building the Actix application.
@@ -63,10 +94,67 @@ impl Application {
 		self.port
 	}
 
-    /// Run the Actix application until it is stopped
+	/// Run the Actix application until it is stopped by a SIGINT or SIGTERM
+	/// signal, then shut down gracefully.
 	pub async fn run_until_stopped(self) -> Result<()> {
-		let application = self.server.await?;
-		return Ok(application);
+		self.run_until(shutdown_signal()).await
+	}
+
+	/// Run the Actix application until `shutdown` resolves
+	///
+	/// # Graceful Shutdown
+	///
+	/// On shutdown the server stops accepting connections and finishes any in
+	/// flight requests before the database pool is closed. Thing event streams
+	/// and WebSockets never finish on their own, so they are ended first.
+	///
+	/// # Parameter
+	///
+	/// * `shutdown` - A future that resolves when the application should stop
+	pub async fn run_until(self, shutdown: impl Future<Output = ()>) -> Result<()> {
+		let handle = self.server.handle();
+		let mut server = self.server;
+
+		tokio::select! {
+			result = &mut server => result?,
+			_ = shutdown => {
+				tracing::info!("Shutting down API server, finishing in flight requests");
+				// Only errors if there are no subscriptions to end
+				let _ = self.shutdown.send(true);
+				// The stop command is processed by the server future, so poll both
+				let (_, result) = tokio::join!(handle.stop(true), server);
+				result?;
+			}
+		}
+
+		self.pool.close().await;
+		tracing::info!("API server shut down");
+
+		Ok(())
+	}
+}
+
+/// Resolve when the process receives a SIGINT (ctrl + c) or SIGTERM signal
+async fn shutdown_signal() {
+	let interrupt = async {
+		tokio::signal::ctrl_c()
+			.await
+			.expect("Failed to install the SIGINT handler");
+	};
+
+	#[cfg(unix)]
+	let terminate = async {
+		tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+			.expect("Failed to install the SIGTERM handler")
+			.recv()
+			.await;
+	};
+	#[cfg(not(unix))]
+	let terminate = std::future::pending::<()>();
+
+	tokio::select! {
+		_ = interrupt => {},
+		_ = terminate => {},
 	}
 }
 
@@ -75,35 +163,583 @@ impl Application {
 ///
 /// # Get database connection pool
 ///
-/// This function initiates a connection pool and then runs the migrations
+/// This function initiates a connection pool, bounded by the database
+/// `max_connections` and `acquire_timeout_seconds` settings, connects and runs
+/// the migrations, unless `run_migrations` is false, then checks the connection
+/// is encrypted if `require_ssl` is set. Connecting and migrating is retried
+/// `startup_retries` times, waiting
+/// `retry_delay_ms` and doubling the wait each retry, so the server can start
+/// before the database is ready.
 pub async fn get_connection_pool(database: &DatabaseSettings) -> Result<PgPool> {
+	let pool_options = PgPoolOptions::new()
+		.max_connections(database.max_connections)
+		.acquire_timeout(Duration::from_secs(database.acquire_timeout_seconds));
 	let connection_pool =
-		PgPoolOptions::new().connect_lazy_with(database.connection());
-	tracing::info!("Connected to database: {}", database.connection_url());
-	sqlx::migrate!("./migrations")
-		.run(&connection_pool)
-		.await
-		.expect("Failed to migrate database");
+		with_statement_timeout(pool_options, database.statement_timeout_ms)
+			.connect_lazy_with(database.connection());
+	let retry_delay = Duration::from_millis(database.retry_delay_ms);
+	retry_with_backoff(database.startup_retries, retry_delay, || async {
+		connection_pool.acquire().await?;
+		if database.run_migrations {
+			migrate(&connection_pool).await?;
+		}
+		Ok(())
+	})
+	.await?;
+	verify_ssl(&connection_pool, database.require_ssl).await?;
+	tracing::info!("Connected to database: {}", database.redacted_connection_url());
 	Ok(connection_pool)
 }
 
-/// Run the Actix server
-fn run(listener: TcpListener, database_pool: PgPool) -> Result<Server> {
+/// Run the `./migrations` not yet applied to the `pool` database
+pub async fn migrate(pool: &PgPool) -> Result<()> {
+	sqlx::migrate!("./migrations").run(pool).await?;
+	tracing::info!("Database migrations applied");
+	Ok(())
+}
+
+/// Call `attempt` until it succeeds or has been retried `retries` times,
+/// returning the successful value or the last error. The first retry waits
+/// `delay`, which doubles for each retry after it.
+pub async fn retry_with_backoff<T, F, Fut>(retries: u32, delay: Duration, mut attempt: F) -> Result<T>
+where
+	F: FnMut() -> Fut,
+	Fut: Future<Output = Result<T>>,
+{
+	let mut delay = delay;
+	for retry in 1..=retries {
+		match attempt().await {
+			Ok(value) => return Ok(value),
+			Err(error) => {
+				tracing::warn!(
+					retry,
+					retries,
+					delay_ms = delay.as_millis() as u64,
+					"Database connection failed, retrying: {error}"
+				);
+				tokio::time::sleep(delay).await;
+				delay = delay.saturating_mul(2);
+			}
+		}
+	}
+
+	attempt().await
+}
+
+/// Fail fast if `require_ssl` is set but the pool can not make an encrypted
+/// connection, rather than finding out on the first request. The pool is lazy,
+/// so this is also the first time it connects to the database.
+///
+/// # Parameters
+///
+/// * `pool` - The database connection pool to check
+/// * `require_ssl` - The database `require_ssl` setting, nothing is checked if false
+pub async fn verify_ssl(pool: &PgPool, require_ssl: bool) -> Result<()> {
+	if !require_ssl {
+		return Ok(());
+	}
+
+	let is_encrypted = sqlx::query_scalar!(
+		r#"
+			SELECT ssl AS "ssl!"
+			FROM pg_stat_ssl
+			WHERE pid = pg_backend_pid()
+		"#
+	)
+	.fetch_one(pool)
+	.await
+	.map_err(|error| Error::DatabaseSslRequired(error.to_string()))?;
+
+	if !is_encrypted {
+		return Err(Error::DatabaseSslRequired(
+			"the database negotiated an unencrypted connection".to_string(),
+		));
+	}
+
+	Ok(())
+}
+
+/// Set the Postgres `statement_timeout` on each new pool connection, so a
+/// runaway query is cancelled instead of tying up the connection.
+///
+/// # Parameters
+///
+/// * `pool_options` - Pool options to add the `after_connect` hook to
+/// * `statement_timeout_ms` - Timeout in milliseconds, `0` disables the timeout
+pub fn with_statement_timeout(
+	pool_options: PgPoolOptions,
+	statement_timeout_ms: u64,
+) -> PgPoolOptions {
+	pool_options.after_connect(move |connection, _metadata| {
+		Box::pin(async move {
+			sqlx::query(&format!("SET statement_timeout = {statement_timeout_ms}"))
+				.execute(connection)
+				.await?;
+			Ok(())
+		})
+	})
+}
+
+/// Thing changes buffered for each subscriber, a subscriber falling further
+/// behind misses the oldest changes
+pub const THING_CHANGES_CAPACITY: usize = 256;
+
+/// Times the Thing changes listener retries connecting, before giving up
+const LISTEN_RETRIES: u32 = 8;
+
+/// Delay before the first Thing changes listener retry, doubling each retry
+const LISTEN_RETRY_DELAY: Duration = Duration::from_millis(250);
+
+/// Listen for Thing changes on the `pool` database, logging each change and
+/// forwarding it to the `changes` subscribers, i.e. event stream handlers
+///
+/// The listener connects with its own connection, so it does not hold one of
+/// the `pool` connections for the life of the server. If the listener can not
+/// connect, or its connection is lost, it reconnects with `retry_with_backoff`
+/// and only stops once every retry has failed or the `pool` is closed. A
+/// change that can not be decoded is logged and skipped, and a change with no
+/// subscribers is only logged.
+pub async fn forward_thing_changes(pool: PgPool, changes: broadcast::Sender<ThingChange>) {
+	loop {
+		let listen = || things::listen_changes(&pool);
+		let stream = match retry_with_backoff(LISTEN_RETRIES, LISTEN_RETRY_DELAY, listen).await {
+			Ok(stream) => stream,
+			Err(error) => {
+				tracing::error!("Failed to listen for Thing changes, giving up: {error}");
+				return;
+			}
+		};
+		futures::pin_mut!(stream);
+
+		while let Some(change) = stream.next().await {
+			match change {
+				Ok(change) => {
+					tracing::info!(action = ?change.action, thing_id = %change.id, "Thing changed");
+					// Only errors if there are no subscribers
+					let _ = changes.send(change);
+				}
+				Err(error) => tracing::error!("Failed to receive a Thing change: {error}"),
+			}
+		}
+
+		if pool.is_closed() {
+			return;
+		}
+		tracing::warn!("Thing changes listener disconnected, reconnecting");
+	}
+}
+
+/// Run the Actix server on a pre-bound `listener`, returning the server and the
+/// port it is bound to.
+///
+/// # Run
+///
+/// Binding to port `0` lets the OS assign an unused port, which is returned so
+/// callers, i.e. tests, can find the server. Request bodies over the application
+/// `max_body_bytes` are rejected with a `413 Payload Too Large`. Responses are
+/// gzip, brotli or zstd compressed when the request `Accept-Encoding` allows it.
+///
+/// # Parameters
+///
+/// * `listener` - A TCP listener already bound to the server address
+/// * `configuration` - Server configuration struct
+/// * `database_pool` - SQLX connection pool
+/// * `shutting_down` - Set when the server starts shutting down, ending the
+///   Thing event subscriptions
+pub fn run(
+	listener: TcpListener,
+	configuration: &Configuration,
+	database_pool: PgPool,
+	shutting_down: ShuttingDown,
+) -> Result<(Server, u16)> {
+	let port = listener.local_addr()?.port();
+	let max_body_bytes = configuration.application.max_body_bytes;
+	// Thing handlers use the repository rather than the pool directly
+	let thing_repository: Arc<dyn ThingRepository> =
+		Arc::new(PgThingRepository::new(database_pool.clone()));
+	let thing_repository = Data::from(thing_repository);
+	// Thing changes from the database, forwarded to each subscriber
+	let (thing_changes, _) = broadcast::channel(THING_CHANGES_CAPACITY);
+	tokio::spawn(forward_thing_changes(database_pool.clone(), thing_changes.clone()));
+	let thing_changes = Data::new(thing_changes);
+	let shutting_down = Data::new(shutting_down);
+	// Saved responses of requests with an `Idempotency-Key` header
+	let idempotency_store = Data::new(IdempotencyStore::new(
+		Duration::from_secs(configuration.application.idempotency_ttl_seconds),
+		database_pool.clone(),
+	));
 	// Wrap database pool around Actix Data type
 	let database = Data::new(database_pool);
+	// Runtime environment, reported by the info handler
+	let environment = Data::new(configuration.application.runtime_environment);
+	// Default and maximum page sizes, for the index handlers
+	let pagination = Data::new(configuration.application.pagination.clone());
+	// API key lookups, shared by the workers
+	let api_key_cache = Data::new(ApiKeyCache::default());
+	// Client request buckets, shared by the workers
+	let rate_limiter = Data::new(RateLimiter::new(configuration.application.rate_limit_per_minute));
 	// Actix server
-	let server = HttpServer::new(move || {
+	let mut server = HttpServer::new(move || {
 		App::new()
+			// Compress responses to the request `Accept-Encoding`, event streams
+			// opt out with a `Content-Encoding: identity` header
+			.wrap(middleware::Compress::default())
+			// Reject clients over the rate limit, keyed by the authenticated API key
+			.wrap(middleware::from_fn(rate_limit::rate_limit))
+			// Authenticate an `X-Api-Key` header, inside the tracing span
+			.wrap(middleware::from_fn(api_key::api_key))
 			// Actix tracing log middleware
-			.wrap(TracingLogger::default())
+			.wrap(TracingLogger::<RequestIdRootSpanBuilder>::new())
+			// Read or generate the `X-Request-Id`, before the tracing root span
+			.wrap(middleware::from_fn(request_id::request_id))
 			// Trim (normalise) trailing slashes `/`
 			.wrap(middleware::NormalizePath::trim())
 			// Configure API V1 scope
-			.service(web::scope("/api/v1").configure(api::v1))
+			.service(web::scope(api::V1_SCOPE).configure(api::v1))
+			// Configure API V2 scope
+			.service(web::scope(api::V2_SCOPE).configure(api::v2))
+			// JSON `404` or `405` for any request that does not match a route
+			.default_service(web::to(fallback::unmatched))
 			// Attach database to the Actix application state
 			.app_data(database.clone())
-	})
-	.listen(listener)?
-	.run();
-	Ok(server)
+			.app_data(thing_repository.clone())
+			.app_data(environment.clone())
+			.app_data(pagination.clone())
+			.app_data(api_key_cache.clone())
+			.app_data(rate_limiter.clone())
+			.app_data(idempotency_store.clone())
+			.app_data(thing_changes.clone())
+			.app_data(shutting_down.clone())
+			// JSON `400` for a malformed path parameter, i.e. a `{thing_id}` Uuid
+			.app_data(web::PathConfig::default().error_handler(handlers::path_error_handler))
+			// Limit the size of form, json and raw request bodies
+			.app_data(web::FormConfig::default().limit(max_body_bytes))
+			.app_data(web::JsonConfig::default().limit(max_body_bytes))
+			.app_data(web::PayloadConfig::new(max_body_bytes))
+	});
+	// Pin the worker count when set, otherwise Actix starts one per CPU
+	if let Some(workers) = configuration.application.workers {
+		server = server.workers(workers);
+	}
+	let server = server
+		// Bound idle and slow connections
+		.keep_alive(Duration::from_secs(configuration.application.keep_alive_seconds))
+		.client_request_timeout(Duration::from_secs(configuration.application.client_timeout_seconds))
+		.listen(listener)?
+		// Signals are handled by `Application::run_until_stopped`, so the pool is
+		// closed after the server stops
+		.disable_signals()
+		.run();
+	Ok((server, port))
+}
+
+#[cfg(test)]
+pub mod tests {
+	// Bring module functions into test scope
+	use super::*;
+
+	// Override with more flexible error
+	pub type Result<T> = core::result::Result<T, Error>;
+	pub type Error = Box<dyn std::error::Error>;
+
+	use actix_web::ResponseError;
+	use sqlx::postgres::{PgConnectOptions, PgSslMode};
+
+	// These tests expect the test database server to have ssl turned off
+
+	#[sqlx::test]
+	async fn verify_ssl_errors_on_unencrypted_connection(pool: PgPool) -> Result<()> {
+		//-- Execute Function (Act)
+		let error = verify_ssl(&pool, true).await.unwrap_err();
+
+		//-- Checks (Assertions)
+		assert!(matches!(error, crate::error::Error::DatabaseSslRequired(_)));
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn verify_ssl_errors_when_ssl_can_not_be_negotiated(
+		pool_options: PgPoolOptions,
+		connect_options: PgConnectOptions,
+	) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		// The same ssl mode `DatabaseSettings::connection` uses for `require_ssl`
+		let pool = pool_options.connect_lazy_with(connect_options.ssl_mode(PgSslMode::Require));
+
+		//-- Execute Function (Act)
+		let error = verify_ssl(&pool, true).await.unwrap_err();
+
+		//-- Checks (Assertions)
+		assert!(matches!(error, crate::error::Error::DatabaseSslRequired(_)));
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn verify_ssl_is_skipped_when_not_required(pool: PgPool) -> Result<()> {
+		//-- Execute Function (Act)
+		let result = verify_ssl(&pool, false).await;
+
+		//-- Checks (Assertions)
+		assert!(result.is_ok());
+		// The unencrypted connection is still usable
+		sqlx::query("SELECT 1").execute(&pool).await?;
+
+		Ok(())
+	}
+
+	#[sqlx::test(migrations = false)]
+	async fn migrate_creates_the_tables(pool: PgPool) -> Result<()> {
+		//-- Execute Function (Act)
+		migrate(&pool).await?;
+		// Migrations already applied are skipped
+		migrate(&pool).await?;
+
+		//-- Checks (Assertions)
+		let count = sqlx::query_scalar!("SELECT COUNT(*) FROM things")
+			.fetch_one(&pool)
+			.await?;
+		assert_eq!(count, Some(0));
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn thing_changes_are_forwarded_to_subscribers(pool: PgPool) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let (changes, mut subscriber) = broadcast::channel(THING_CHANGES_CAPACITY);
+		tokio::spawn(forward_thing_changes(pool.clone(), changes));
+
+		//-- Execute Function (Act)
+		// The listener connects in the background, so insert until it is ready,
+		// a late change may be for any of the inserted Things
+		let mut inserted_ids = Vec::new();
+		let change = tokio::time::timeout(Duration::from_secs(5), async {
+			loop {
+				let name = crate::domain::ThingName::parse(format!("Forwarded {}", uuid::Uuid::now_v7()))?;
+				let thing = crate::domain::ThingBuilder::new(name).build()?;
+				things::insert(&thing, &pool).await?;
+				inserted_ids.push(thing.id);
+				if let Ok(change) = tokio::time::timeout(Duration::from_millis(200), subscriber.recv()).await {
+					return Ok::<_, Error>(change?);
+				}
+			}
+		})
+		.await??;
+
+		//-- Checks (Assertions)
+		assert_eq!(change.action, things::ThingChangeAction::Insert);
+		assert!(inserted_ids.contains(&change.id));
+
+		Ok(())
+	}
+
+	#[sqlx::test(migrations = false)]
+	async fn name_index_migration_renames_names_differing_by_case(pool: PgPool) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let mut migrator = sqlx::migrate!("./migrations");
+		let migrations = migrator.migrations.clone();
+		// Migrate to just before the case insensitive name index
+		migrator.migrations = migrations.iter().filter(|migration| migration.version < 11).cloned().collect();
+		migrator.run(&pool).await?;
+		for (name, created_at) in [("Coffee", "2024-01-01"), ("coffee", "2024-01-02"), ("Tea", "2024-01-03")] {
+			sqlx::query("INSERT INTO things (id, name, created_at) VALUES (uuid_generate_v7(), $1, $2::date)")
+				.bind(name)
+				.bind(created_at)
+				.execute(&pool)
+				.await?;
+		}
+
+		//-- Execute Function (Act)
+		migrator.migrations = migrations;
+		migrator.run(&pool).await?;
+
+		//-- Checks (Assertions)
+		let names: Vec<String> = sqlx::query_scalar("SELECT name FROM things ORDER BY created_at")
+			.fetch_all(&pool)
+			.await?;
+		assert_eq!(names[0], "Coffee");
+		assert!(names[1].starts_with("coffee ") && names[1].len() == "coffee ".len() + 12);
+		assert_eq!(names[2], "Tea");
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn retry_with_backoff_retries_until_it_succeeds() -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let attempts = std::sync::atomic::AtomicU32::new(0);
+
+		//-- Execute Function (Act)
+		let result = retry_with_backoff(3, Duration::from_millis(1), || async {
+			match attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst) {
+				0 | 1 => Err(crate::error::Error::Static("not ready")),
+				_ => Ok(()),
+			}
+		})
+		.await;
+
+		//-- Checks (Assertions)
+		assert!(result.is_ok());
+		assert_eq!(attempts.into_inner(), 3);
+
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn unreachable_database_fails_after_the_configured_retries() -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let mut database = Configuration::parse()?.database;
+		// Nothing listens on port 1, so every connection is refused
+		database.host = "127.0.0.1".to_string();
+		database.port = 1;
+		database.acquire_timeout_seconds = 1;
+		database.startup_retries = 2;
+		database.retry_delay_ms = 100;
+		let started_at = std::time::Instant::now();
+
+		//-- Execute Function (Act)
+		let result = get_connection_pool(&database).await;
+
+		//-- Checks (Assertions)
+		assert!(result.is_err());
+		// The retries wait 100ms then 200ms
+		assert!(started_at.elapsed() >= Duration::from_millis(300));
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn run_returns_the_bound_port(pool: PgPool) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let configuration = Configuration::parse()?;
+		// Binding to port `0` lets the OS assign an unused port
+		let listener = TcpListener::bind("127.0.0.1:0")?;
+
+		//-- Execute Function (Act)
+		let (_shutdown, shutting_down) = watch::channel(false);
+		let (server, port) = run(listener, &configuration, pool, shutting_down)?;
+		let handle = server.handle();
+		tokio::spawn(server);
+		let response = reqwest::get(format!("http://127.0.0.1:{port}{}/ping", api::V1_SCOPE)).await?;
+
+		//-- Checks (Assertions)
+		assert_ne!(port, 0);
+		assert_eq!(200, response.status().as_u16());
+		handle.stop(true).await;
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn application_shuts_down_gracefully(pool: PgPool) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let mut configuration = Configuration::parse()?;
+		// Setting port to `0` lets the OS assign an unused port
+		configuration.application.port = 0;
+		let application = Application::build(configuration, pool.clone()).await?;
+
+		//-- Execute Function (Act)
+		let result = application.run_until(async {}).await;
+
+		//-- Checks (Assertions)
+		assert!(result.is_ok());
+		assert!(pool.is_closed());
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn application_shuts_down_with_an_open_event_stream(pool: PgPool) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let mut configuration = Configuration::parse()?;
+		configuration.application.port = 0;
+		let application = Application::build(configuration, pool).await?;
+		let events_url = format!("http://127.0.0.1:{}{}/things/events", application.port(), api::V1_SCOPE);
+		let (connected, is_connected) = tokio::sync::oneshot::channel();
+		tokio::spawn(async move {
+			let mut response = reqwest::get(events_url).await?;
+			let _ = connected.send(response.chunk().await?);
+			// Hold the stream open until the server ends it
+			while response.chunk().await?.is_some() {}
+			Ok::<_, reqwest::Error>(())
+		});
+
+		//-- Execute Function (Act)
+		let started_at = std::time::Instant::now();
+		let result = application
+			.run_until(async {
+				let _ = is_connected.await;
+			})
+			.await;
+
+		//-- Checks (Assertions)
+		assert!(result.is_ok());
+		// Well inside the Actix 30 second graceful shutdown timeout
+		assert!(started_at.elapsed() < Duration::from_secs(10));
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn application_builds_with_a_worker_count(pool: PgPool) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let mut configuration = Configuration::parse()?;
+		configuration.application.port = 0;
+		configuration.application.workers = Some(2);
+
+		//-- Execute Function (Act)
+		let application = Application::build(configuration, pool).await?;
+		let result = application.run_until(async {}).await;
+
+		//-- Checks (Assertions)
+		assert!(result.is_ok());
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn statement_timeout_is_enforced(
+		pool_options: PgPoolOptions,
+		connect_options: PgConnectOptions,
+	) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		let pool = with_statement_timeout(pool_options, 10)
+			.connect_with(connect_options)
+			.await?;
+
+		//-- Execute Function (Act)
+		let error: crate::error::Error = sqlx::query("SELECT pg_sleep(1)")
+			.execute(&pool)
+			.await
+			.unwrap_err()
+			.into();
+
+		//-- Checks (Assertions)
+		assert!(matches!(error, crate::error::Error::DatabaseTimeout));
+		assert_eq!(504, error.status_code().as_u16());
+
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn cancelled_query_is_not_a_timeout(pool: PgPool) -> Result<()> {
+		//-- Setup and Fixtures (Arrange)
+		// The query cancels itself, as a `pg_cancel_backend` from an admin would
+		let query = "SELECT pg_cancel_backend(pg_backend_pid()), pg_sleep(1)";
+
+		//-- Execute Function (Act)
+		let error: crate::error::Error = sqlx::query(query)
+			.execute(&pool)
+			.await
+			.unwrap_err()
+			.into();
+
+		//-- Checks (Assertions)
+		assert!(matches!(error, crate::error::Error::Database(_)));
+		assert_eq!(500, error.status_code().as_u16());
+
+		Ok(())
+	}
 }
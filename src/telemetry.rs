@@ -31,10 +31,9 @@ use tracing::subscriber::set_global_default;
 use tracing::{debug, Subscriber};
 use tracing_bunyan_formatter::{BunyanFormattingLayer, JsonStorageLayer};
 use tracing_log::LogTracer;
-use tracing_subscriber::filter::filter_fn;
 use tracing_subscriber::fmt::format::FmtSpan;
 use tracing_subscriber::fmt::MakeWriter;
-use tracing_subscriber::{layer::SubscriberExt, EnvFilter, Layer, Registry};
+use tracing_subscriber::{layer::SubscriberExt, EnvFilter, Registry};
 
 /// Compose multiple subscriber layers into a `tracing` subscriber registry.
 /// 
@@ -49,13 +48,14 @@ use tracing_subscriber::{layer::SubscriberExt, EnvFilter, Layer, Registry};
 ///
 /// * `name` - Name to append to all span and event formatted records.
 /// * `sink` - Write formatted records to the sink.
-/// * `env` - Takes a personal_ledger_server::configuration::Env enum value
+/// * `log_format` - Takes a personal_ledger_server::configuration::LogFormat enum value,
+///   only the layers for that format are added
 /// * `log_level` - Takes a Takes a personal_ledger_server::configuration::LogLevels enum value
 ///
 pub fn get_tracing_subscriber<Sink>(
     name: String,
     sink: Sink,
-    env: configuration::Environment,
+    log_format: configuration::LogFormat,
     log_level: configuration::LogLevels,
 ) -> impl Subscriber + Sync + Send
 where
@@ -66,19 +66,23 @@ where
     let env_filter =
         EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(log_level));
 
-    // When running in a development environment, output records to pretty std.out
-    let emit_pretty = env == configuration::Environment::Development;
-    let pretty_formatting_layer = tracing_subscriber::fmt::layer()
-        // .pretty()
-        .with_span_events(FmtSpan::NEW | FmtSpan::CLOSE) // Capture Actix span events
-        .with_filter(filter_fn(move |_| emit_pretty));
-
-    // When running in a Production environment, output records in JSON
-    let emit_bunyan = env == configuration::Environment::Production;
-    let bunyan_json_layer = JsonStorageLayer
-        .with_filter(filter_fn(move |_| emit_bunyan));
-    let bunyan_formatting_layer =
-        BunyanFormattingLayer::new(name, sink).with_filter(filter_fn(move |_| emit_bunyan));
+    // Output records to the sink as pretty text or bunyan JSON, a `None` layer
+    // is skipped by the registry
+    let (pretty_formatting_layer, bunyan_formatting_layer) = match log_format {
+        configuration::LogFormat::Pretty => (
+            Some(
+                tracing_subscriber::fmt::layer()
+                    // .pretty()
+                    .with_span_events(FmtSpan::NEW | FmtSpan::CLOSE) // Capture Actix span events
+                    .with_writer(sink),
+            ),
+            None,
+        ),
+        configuration::LogFormat::Json => (None, Some(BunyanFormattingLayer::new(name, sink))),
+    };
+    // The bunyan formatter reads span fields from the JSON storage layer
+    let bunyan_json_layer =
+        (log_format == configuration::LogFormat::Json).then_some(JsonStorageLayer);
 
     // TODO: Add console subscriber
     // let console_subscriber =
@@ -122,3 +126,64 @@ pub fn init_tracing(
 
     Ok(())
 }
+
+//-- Unit Tests
+#[cfg(test)]
+pub mod tests {
+    // Bring module functions into test scope
+    use super::*;
+
+    use std::sync::{Arc, Mutex};
+
+    /// A writer appending formatted records to a shared buffer
+    #[derive(Clone, Default)]
+    struct BufferWriter(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for BufferWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Log an event with a subscriber in `log_format`, returning the output
+    fn log_with_format(log_format: configuration::LogFormat) -> String {
+        let buffer = BufferWriter::default();
+        let sink = buffer.clone();
+        let subscriber = get_tracing_subscriber(
+            "test".to_string(),
+            move || sink.clone(),
+            log_format,
+            configuration::LogLevels::Info,
+        );
+
+        tracing::subscriber::with_default(subscriber, || tracing::info!("log format test"));
+
+        let output = buffer.0.lock().unwrap().clone();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn json_format_writes_bunyan_records() {
+        //-- Execute Function (Act)
+        let output = log_with_format(configuration::LogFormat::Json);
+
+        //-- Checks (Assertions)
+        let record: serde_json::Value = serde_json::from_str(output.lines().next().unwrap()).unwrap();
+        assert_eq!(record["msg"], "log format test");
+        assert_eq!(record["name"], "test");
+    }
+
+    #[test]
+    fn pretty_format_writes_text_records() {
+        //-- Execute Function (Act)
+        let output = log_with_format(configuration::LogFormat::Pretty);
+
+        //-- Checks (Assertions)
+        assert!(output.contains("log format test"));
+        assert!(serde_json::from_str::<serde_json::Value>(output.lines().next().unwrap()).is_err());
+    }
+}
@@ -0,0 +1,73 @@
+use crate::helpers::*;
+
+use personal_ledger_server::services::api_keys;
+use sqlx::{Pool, Postgres};
+
+// Override with more flexible error
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>;
+
+#[sqlx::test]
+async fn ping_with_a_valid_api_key_returns_200(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+    let (_, key) = api_keys::issue("billing", &app.database_pool).await?;
+
+    //-- Execute Test (Act)
+    let response = client
+        .get(format!("{}/ping", &app.address))
+        .header("X-Api-Key", key)
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    // Check http status is ok (200)
+    assert_eq!(200, response.status().as_u16());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn ping_with_an_unknown_api_key_returns_401(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+
+    //-- Execute Test (Act)
+    let response = client
+        .get(format!("{}/ping", &app.address))
+        .header("X-Api-Key", "unknown")
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    // Check http status is unauthorized (401)
+    assert_eq!(401, response.status().as_u16());
+    let body: serde_json::Value = serde_json::from_str(&response.text().await?)?;
+    assert_eq!(body["code"], "ApiKeyInvalid");
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn ping_with_a_revoked_api_key_returns_401(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+    let (id, key) = api_keys::issue("billing", &app.database_pool).await?;
+    api_keys::revoke(&id, &app.database_pool).await?;
+
+    //-- Execute Test (Act)
+    let response = client
+        .get(format!("{}/ping", &app.address))
+        .header("X-Api-Key", key)
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    // Check http status is unauthorized (401)
+    assert_eq!(401, response.status().as_u16());
+
+    Ok(())
+}
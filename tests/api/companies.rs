@@ -0,0 +1,63 @@
+// Override with more flexible error
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>;
+
+use sqlx::{Pool, Postgres};
+
+#[sqlx::test]
+async fn company_updated_at_advances_on_a_direct_sql_update(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // Created a day ago, so the update is later whatever the clock resolution
+    let company = sqlx::query!(
+        r#"
+            INSERT INTO companies (name, created_at, updated_at)
+            VALUES ('Direct Edit', now() - interval '1 day', now() - interval '1 day')
+            RETURNING id, created_at
+        "#
+    )
+    .fetch_one(&database_pool)
+    .await?;
+
+    //-- Execute Test (Act)
+    // Update with SQL rather than a service, as a direct edit would
+    let updated = sqlx::query!(
+        r#"
+            UPDATE companies
+            SET description = 'Edited outside of the service'
+            WHERE id = $1
+            RETURNING created_at, updated_at
+        "#,
+        company.id
+    )
+    .fetch_one(&database_pool)
+    .await?;
+
+    //-- Checks (Assertions)
+    assert_eq!(updated.created_at, company.created_at);
+    assert!(updated.updated_at > company.created_at);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn company_timestamps_default_to_now(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let before = chrono::Utc::now() - chrono::Duration::seconds(1);
+
+    //-- Execute Test (Act)
+    let company = sqlx::query!(
+        r#"
+            INSERT INTO companies (name)
+            VALUES ('Defaulted')
+            RETURNING created_at, updated_at
+        "#
+    )
+    .fetch_one(&database_pool)
+    .await?;
+
+    //-- Checks (Assertions)
+    assert!(company.created_at > before);
+    assert_eq!(company.created_at, company.updated_at);
+
+    Ok(())
+}
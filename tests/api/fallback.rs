@@ -0,0 +1,113 @@
+use crate::helpers::*;
+
+use sqlx::{Pool, Postgres};
+
+// Override with more flexible error
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>;
+
+#[sqlx::test]
+async fn unknown_path_returns_404_json(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+
+    //-- Execute Test (Act)
+    let response = client
+        .get(format!("{}/does-not-exist", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute unknown path request.");
+
+    //-- Checks (Assertions)
+    assert_eq!(404, response.status().as_u16());
+    let body: serde_json::Value = serde_json::from_str(&response.text().await?)?;
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "error": "not found",
+            "code": "NOT_FOUND",
+            "path": "/api/v1/does-not-exist"
+        })
+    );
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn unsupported_method_returns_405_json(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+
+    //-- Execute Test (Act)
+    let response = client
+        .patch(format!("{}/things", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute unsupported method request.");
+
+    //-- Checks (Assertions)
+    assert_eq!(405, response.status().as_u16());
+    let allow = response
+        .headers()
+        .get(reqwest::header::ALLOW)
+        .expect("Missing Allow header")
+        .to_str()?
+        .to_string();
+    assert!(allow.contains("GET"));
+    assert!(allow.contains("POST"));
+    let body: serde_json::Value = serde_json::from_str(&response.text().await?)?;
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "error": "method not allowed",
+            "code": "METHOD_NOT_ALLOWED",
+            "path": "/api/v1/things"
+        })
+    );
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn delete_ping_returns_405_json(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+
+    //-- Execute Test (Act)
+    let response = client
+        .delete(format!("{}/ping", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute delete ping request.");
+
+    //-- Checks (Assertions)
+    assert_eq!(405, response.status().as_u16());
+    assert_eq!(response.headers().get(reqwest::header::ALLOW).unwrap(), "GET");
+    let body: serde_json::Value = serde_json::from_str(&response.text().await?)?;
+    assert_eq!(body["error"], "method not allowed");
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn post_things_count_allows_only_get(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+
+    //-- Execute Test (Act)
+    let response = client
+        .post(format!("{}/things/count", &app.address))
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    // `count` is not a `{thing_id}`, so the id methods are not allowed
+    assert_eq!(405, response.status().as_u16());
+    assert_eq!(response.headers().get(reqwest::header::ALLOW).unwrap(), "GET");
+
+    Ok(())
+}
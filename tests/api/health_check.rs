@@ -0,0 +1,35 @@
+use crate::helpers::*;
+
+use personal_ledger_server::handlers::health_check::HealthCheck;
+use sqlx::{Pool, Postgres};
+
+// Override with more flexible error
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>;
+
+#[sqlx::test]
+async fn health_check_returns_200_with_database_up(
+    database_pool: Pool<Postgres>,
+) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+
+    //-- Execute Test (Act)
+    let response = client
+        // Use the returned application address
+        .get(format!("{}/health_check", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute health check request.");
+
+    //-- Checks (Assertions)
+    // Check http status is ok (200)
+    assert_eq!(200, response.status().as_u16());
+    // Check response body reports the database is up
+    let health_check: HealthCheck = serde_json::from_str(&response.text().await?)?;
+    assert_eq!(health_check.status, "ok");
+    assert_eq!(health_check.database, "up");
+
+    Ok(())
+}
@@ -1,6 +1,8 @@
 use once_cell::sync::Lazy;
+use futures::{Stream, StreamExt};
 use personal_ledger_server::{
-	configuration::{Configuration, Environment, LogLevels},
+	configuration::{Configuration, LogFormat, LogLevels},
+	domain::Thing,
 	startup::Application,
 	telemetry,
 };
@@ -18,7 +20,7 @@ static TRACING: Lazy<()> = Lazy::new(|| {
 		let tracing_subscriber = telemetry::get_tracing_subscriber(
 			subscriber_name,
 			std::io::stdout,
-			Environment::Development,
+			LogFormat::Pretty,
 			default_filter_level,
 		);
 		let _ = telemetry::init_tracing(tracing_subscriber, default_filter_level);
@@ -26,19 +28,28 @@ static TRACING: Lazy<()> = Lazy::new(|| {
 		let subscriber = telemetry::get_tracing_subscriber(
 			subscriber_name,
 			std::io::sink,
-			Environment::Development,
+			LogFormat::Pretty,
 			default_filter_level,
 		);
 		let _ = telemetry::init_tracing(subscriber, default_filter_level);
 	};
 });
 
+#[allow(dead_code)]
 pub struct TestApp {
 	pub address: String,
 	pub database_pool: PgPool,
 }
 
 pub async fn spawn_app(database_pool: Pool<Postgres>) -> Result<TestApp> {
+	spawn_app_with(database_pool, |_| {}).await
+}
+
+/// Spawn the application after `configure` has changed the parsed configuration
+pub async fn spawn_app_with(
+	database_pool: Pool<Postgres>,
+	configure: impl FnOnce(&mut Configuration),
+) -> Result<TestApp> {
 	Lazy::force(&TRACING);
 
 	// Parse configuration files
@@ -46,6 +57,7 @@ pub async fn spawn_app(database_pool: Pool<Postgres>) -> Result<TestApp> {
 		let mut c = Configuration::parse().expect("Failed to read configuration.");
         // Setting port to `0` avoids conflicts as the OS will assign an unused 
         c.application.port = 0;
+		configure(&mut c);
 		c
 	};
 
@@ -55,10 +67,47 @@ pub async fn spawn_app(database_pool: Pool<Postgres>) -> Result<TestApp> {
 			.await
 			.expect("Failed to build test application.");
 	let address = format!("http://localhost:{}/api/v1", application.port());
-	let _ = tokio::spawn(application.run_until_stopped());
+	tokio::spawn(application.run_until_stopped());
 
 	Ok(TestApp {
 		address,
 		database_pool,
 	})
 }
+
+/// Create Things through the API until an event arrives on `events`, returning
+/// the ids of every Thing created and the first event.
+///
+/// The change listener connects in the background, so Things created before it
+/// is listening have no event. An event can also arrive after the next Thing is
+/// created, so it may be for any of the Things created, not only the last.
+pub async fn create_things_until_an_event(
+	app: &TestApp,
+	mut events: impl Stream<Item = String> + Unpin,
+) -> Result<(Vec<uuid::Uuid>, String)> {
+	let client = reqwest::Client::new();
+	let mut created_ids = Vec::new();
+
+	let event = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+		loop {
+			let created = client
+				.post(format!("{}/things", &app.address))
+				.header("Content-Type", "application/x-www-form-urlencoded")
+				.body(format!("name=Event%20{}", uuid::Uuid::now_v7()))
+				.send()
+				.await?
+				.text()
+				.await?;
+			let thing: Thing = serde_json::from_str(&created)?;
+			created_ids.push(thing.id);
+
+			let event = tokio::time::timeout(std::time::Duration::from_millis(200), events.next()).await;
+			if let Ok(event) = event {
+				return event.ok_or_else(|| Error::from("The event stream ended."));
+			}
+		}
+	})
+	.await??;
+
+	Ok((created_ids, event))
+}
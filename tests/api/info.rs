@@ -0,0 +1,40 @@
+use crate::helpers::*;
+
+use personal_ledger_server::configuration::Environment;
+use personal_ledger_server::handlers::info::Info;
+use sqlx::{Pool, Postgres};
+
+// Override with more flexible error
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>;
+
+#[sqlx::test]
+async fn info_reports_version_migrations_and_environment(
+    database_pool: Pool<Postgres>,
+) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app_with(database_pool, |configuration| {
+        configuration.application.runtime_environment = Environment::Testing;
+    })
+    .await?;
+    let client = reqwest::Client::new();
+
+    //-- Execute Test (Act)
+    let response = client
+        // Use the returned application address
+        .get(format!("{}/info", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute info request.");
+
+    //-- Checks (Assertions)
+    // Check http status is ok (200)
+    assert_eq!(200, response.status().as_u16());
+    let info: Info = serde_json::from_str(&response.text().await?)?;
+    assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+    assert_eq!(info.environment, "testing");
+    // The test database has every migration applied
+    assert_eq!(info.migrations_applied, sqlx::migrate!("./migrations").iter().count() as i64);
+
+    Ok(())
+}
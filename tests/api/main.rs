@@ -1,3 +1,10 @@
+mod api_key;
+mod companies;
+mod fallback;
+mod health_check;
+mod info;
 mod ping;
+mod rate_limit;
+mod request_id;
 mod helpers;
 mod things;
\ No newline at end of file
@@ -15,7 +15,7 @@ async fn ping_returns_200(database_pool: Pool<Postgres>) -> Result<()> {
     //-- Execute Test (Act)
     let response = client
         // Use the returned application address
-        .get(&format!("{}/ping", &app.address))
+        .get(format!("{}/ping", &app.address))
         .send()
         .await
         .expect("Failed to execute ping request.");
@@ -30,4 +30,25 @@ async fn ping_returns_200(database_pool: Pool<Postgres>) -> Result<()> {
     assert_eq!(Some(7), response.content_length());
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[sqlx::test]
+async fn ping_is_served_by_v1_and_v2(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+    // The test app address is the version 1 scope
+    let v2_address = app.address.replace("/api/v1", "/api/v2");
+
+    //-- Execute Test (Act)
+    let v1_response = client.get(format!("{}/ping", &app.address)).send().await?;
+    let v2_response = client.get(format!("{v2_address}/ping")).send().await?;
+
+    //-- Checks (Assertions)
+    // Check both versions respond ok (200) with the same body
+    assert_eq!(200, v1_response.status().as_u16());
+    assert_eq!(200, v2_response.status().as_u16());
+    assert_eq!(v1_response.text().await?, v2_response.text().await?);
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use crate::helpers::*;
+
+use personal_ledger_server::services::api_keys;
+use sqlx::{Pool, Postgres};
+
+// Override with more flexible error
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>;
+
+#[sqlx::test]
+async fn requests_over_the_rate_limit_return_429(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let rate_limit_per_minute = 3;
+    let app = spawn_app_with(database_pool, |configuration| {
+        configuration.application.rate_limit_per_minute = rate_limit_per_minute;
+    })
+    .await?;
+    let client = reqwest::Client::new();
+
+    //-- Execute Test (Act)
+    let mut statuses = Vec::new();
+    let mut retry_after = None;
+    for _ in 0..=rate_limit_per_minute {
+        let response = client
+            .get(format!("{}/ping", &app.address))
+            .send()
+            .await?;
+        statuses.push(response.status().as_u16());
+        retry_after = response.headers().get("Retry-After").cloned();
+    }
+
+    //-- Checks (Assertions)
+    // Check the requests within the limit are ok and the last is too many (429)
+    assert_eq!(statuses, vec![200, 200, 200, 429]);
+    // Check the client is told when to retry, one token refills every 20 seconds
+    let retry_after: u64 = retry_after.expect("No Retry-After header").to_str()?.parse()?;
+    assert!(retry_after > 0 && retry_after <= 20);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn api_key_requests_are_limited_by_the_authenticated_key(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let rate_limit_per_minute = 2;
+    let app = spawn_app_with(database_pool, |configuration| {
+        configuration.application.rate_limit_per_minute = rate_limit_per_minute;
+    })
+    .await?;
+    let client = reqwest::Client::new();
+    let (_, key) = api_keys::issue("billing", &app.database_pool).await?;
+
+    //-- Execute Test (Act)
+    let mut key_statuses = Vec::new();
+    for _ in 0..=rate_limit_per_minute {
+        let response = client
+            .get(format!("{}/ping", &app.address))
+            .header("X-Api-Key", &key)
+            .send()
+            .await?;
+        key_statuses.push(response.status().as_u16());
+    }
+    // Unauthenticated requests from the same address have their own bucket
+    let ip_response = client
+        .get(format!("{}/ping", &app.address))
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    assert_eq!(key_statuses, vec![200, 200, 429]);
+    assert_eq!(200, ip_response.status().as_u16());
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use crate::helpers::*;
+
+use sqlx::{Pool, Postgres};
+
+// Override with more flexible error
+pub type Result<T> = core::result::Result<T, Error>;
+pub type Error = Box<dyn std::error::Error>;
+
+#[sqlx::test]
+async fn response_has_a_generated_request_id(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+
+    //-- Execute Test (Act)
+    let response = client
+        .get(format!("{}/ping", &app.address))
+        .send()
+        .await
+        .expect("Failed to execute ping request.");
+
+    //-- Checks (Assertions)
+    let request_id = response
+        .headers()
+        .get("x-request-id")
+        .expect("Missing X-Request-Id header")
+        .to_str()?;
+    // A generated request id is a Uuid v7
+    assert_eq!(uuid::Uuid::parse_str(request_id)?.get_version_num(), 7);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn supplied_request_id_is_echoed(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+
+    //-- Execute Test (Act)
+    let response = client
+        .get(format!("{}/ping", &app.address))
+        .header("X-Request-Id", "upstream-request-42")
+        .send()
+        .await
+        .expect("Failed to execute ping request.");
+
+    //-- Checks (Assertions)
+    assert_eq!(
+        response.headers().get("x-request-id").map(|value| value.to_str()).transpose()?,
+        Some("upstream-request-42")
+    );
+
+    Ok(())
+}
@@ -3,7 +3,7 @@ pub type Result<T> = core::result::Result<T, Error>;
 pub type Error = Box<dyn std::error::Error>;
 
 //-- Common helper modules
-use crate::helpers::spawn_app;
+use crate::helpers::{create_things_until_an_event, spawn_app, spawn_app_with};
 // use personal_ledger_server::domain::ThingName;
 
 //-- External crate development dependencies
@@ -12,7 +12,9 @@ use fake::Fake;
 use sqlx::{Pool, Postgres};
 use url::form_urlencoded;
 use actix_web::body::MessageBody;
-use personal_ledger_server::domain::{Thing, ThingDescription, ThingName};
+use personal_ledger_server::domain::{Paginated, Thing, ThingBuilder, ThingDescription, ThingName};
+use personal_ledger_server::configuration::Configuration;
+use personal_ledger_server::services::things::{insert, soft_delete_by_id};
 
 #[sqlx::test]
 async fn things_endpoint_works(database_pool: Pool<Postgres>) -> Result<()> {
@@ -23,7 +25,7 @@ async fn things_endpoint_works(database_pool: Pool<Postgres>) -> Result<()> {
     //-- Execute Test (Act)
     let response = client
         // Use the returned application address
-        .get(&format!("{}/things", &app.address))
+        .get(format!("{}/things", &app.address))
         .send()
         .await
         .expect("Failed to execute request.");
@@ -51,7 +53,7 @@ async fn post_things(database_pool: Pool<Postgres>) -> Result<()> {
     //-- Execute Test (Act)
     let response = client
         // Use the returned application address
-        .post(&format!("{}/things", &app.address))
+        .post(format!("{}/things", &app.address))
         .header("Content-Type", "application/x-www-form-urlencoded")
         .body(body)
         .send()
@@ -87,16 +89,793 @@ async fn post_things(database_pool: Pool<Postgres>) -> Result<()> {
     Ok(())
 }
 
-// #[sqlx::test]
-// async fn get_things_index(database_pool: Pool<Postgres>) -> Result<()> {
-//     //-- Setup and Fixtures (Arrange)
-//
-//
-//     //-- Execute Test (Act)
-//
-//
-//     //-- Checks (Assertions)
-//
-//
-//     Ok(())
-// }
\ No newline at end of file
+#[sqlx::test]
+async fn post_things_with_a_repeated_idempotency_key(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+    let name: String = Word().fake();
+    let body = format!("name={name}");
+
+    //-- Execute Test (Act)
+    let mut responses = Vec::new();
+    for _ in 0..2 {
+        let response = client
+            .post(format!("{}/things", &app.address))
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .header("Idempotency-Key", "create-thing-once")
+            .body(body.clone())
+            .send()
+            .await?;
+        responses.push((response.status().as_u16(), response.text().await?));
+    }
+
+    //-- Checks (Assertions)
+    // The retry gets the original response rather than a name conflict
+    assert_eq!(responses[0].0, 200);
+    assert_eq!(responses[0], responses[1]);
+    let rows = sqlx::query_scalar!(r#"SELECT COUNT(*) AS "count!" FROM things"#)
+        .fetch_one(&app.database_pool)
+        .await?;
+    assert_eq!(rows, 1);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn post_things_with_a_concurrent_idempotency_key_inserts_once(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+    let name: String = Word().fake();
+    let body = format!("name={name}");
+
+    //-- Execute Test (Act)
+    let requests = (0..5).map(|_| {
+        client
+            .post(format!("{}/things", &app.address))
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .header("Idempotency-Key", "create-thing-concurrently")
+            .body(body.clone())
+            .send()
+    });
+    let responses = futures::future::try_join_all(requests).await?;
+
+    //-- Checks (Assertions)
+    // Each request gets the response or is told the first is still in progress
+    for response in responses {
+        assert!([200, 409].contains(&response.status().as_u16()));
+    }
+    let rows = sqlx::query_scalar!(r#"SELECT COUNT(*) AS "count!" FROM things"#)
+        .fetch_one(&app.database_pool)
+        .await?;
+    assert_eq!(rows, 1);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn create_thing_with_oversized_body_returns_413(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+    let name: String = Word().fake();
+    // Larger than the default `max_body_bytes` of 64 KiB
+    let description = "a".repeat(128 * 1024);
+
+    //-- Execute Test (Act)
+    let response = client
+        .post(format!("{}/things", &app.address))
+        .form(&[("name", name), ("description", description)])
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    // Check http status is payload too large (413)
+    assert_eq!(413, response.status().as_u16());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn delete_thing_then_get_returns_404(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+    let name: String = Word().fake();
+    let description: String = Sentence(3..7).fake();
+    let body = client
+        .post(format!("{}/things", &app.address))
+        .form(&[("name", name), ("description", description)])
+        .send()
+        .await?
+        .text()
+        .await?;
+    let thing: Thing = serde_json::from_str(&body)?;
+
+    //-- Execute Test (Act)
+    let delete_response = client
+        .delete(format!("{}/things/{}", &app.address, thing.id))
+        .send()
+        .await?;
+    let get_response = client
+        .get(format!("{}/things/{}", &app.address, thing.id))
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    // Check delete http status is no content (204)
+    assert_eq!(204, delete_response.status().as_u16());
+    // Check get http status is not found (404)
+    assert_eq!(404, get_response.status().as_u16());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn delete_things_by_ids(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+    let mut ids = Vec::new();
+    for index in 0..4 {
+        let thing = ThingBuilder::new(ThingName::parse(format!("Bulk delete {index}"))?).build()?;
+        ids.push(insert(&thing, &app.database_pool).await?.id);
+    }
+
+    //-- Execute Test (Act)
+    let response = client
+        .delete(format!("{}/things", &app.address))
+        .header("Content-Type", "application/json")
+        .body(serde_json::to_string(&ids[..3])?)
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    assert_eq!(200, response.status().as_u16());
+    let body: serde_json::Value = serde_json::from_str(&response.text().await?)?;
+    assert_eq!(body, serde_json::json!({ "deleted": 3 }));
+    let remaining = sqlx::query_scalar!("SELECT id FROM things")
+        .fetch_all(&app.database_pool)
+        .await?;
+    assert_eq!(remaining, vec![ids[3]]);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn restore_soft_deleted_thing(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+    let name: String = Word().fake();
+    let description: String = Sentence(3..7).fake();
+    let body = client
+        .post(format!("{}/things", &app.address))
+        .form(&[("name", name), ("description", description)])
+        .send()
+        .await?
+        .text()
+        .await?;
+    let thing: Thing = serde_json::from_str(&body)?;
+    soft_delete_by_id(&thing.id, &app.database_pool).await?;
+    let deleted_page: Paginated<Thing> = serde_json::from_str(
+        &client.get(format!("{}/things", &app.address)).send().await?.text().await?,
+    )?;
+
+    //-- Execute Test (Act)
+    let restore_response = client
+        .post(format!("{}/things/{}/restore", &app.address, thing.id))
+        .send()
+        .await?;
+    let restore_status = restore_response.status().as_u16();
+    let restored_thing: Thing = serde_json::from_str(&restore_response.text().await?)?;
+    let restored_page: Paginated<Thing> = serde_json::from_str(
+        &client.get(format!("{}/things", &app.address)).send().await?.text().await?,
+    )?;
+    let second_restore_response = client
+        .post(format!("{}/things/{}/restore", &app.address, thing.id))
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    // Check the soft deleted Thing is not in the index
+    assert_eq!(deleted_page.total, 0);
+    // Check restore http status is ok (200) and returns the Thing
+    assert_eq!(200, restore_status);
+    assert_eq!(restored_thing.id, thing.id);
+    assert_eq!(restored_thing.deleted_at, None);
+    // Check the restored Thing is back in the index
+    assert_eq!(restored_page.total, 1);
+    assert_eq!(restored_page.data[0].id, thing.id);
+    // Check a Thing that is not soft deleted can not be restored (404)
+    assert_eq!(404, second_restore_response.status().as_u16());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn get_things_index(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+    let random_count: i64 = (5..15).fake::<i64>();
+    for count in 0..random_count {
+        // Thing names are unique
+        let name: String = format!("{} {count}", Word().fake::<String>());
+        let description: String = Sentence(3..7).fake();
+        client
+            .post(format!("{}/things", &app.address))
+            .form(&[("name", name), ("description", description)])
+            .send()
+            .await?;
+    }
+    let random_limit: i64 = (1..random_count).fake::<i64>();
+    let random_offset: i64 = (0..random_count).fake::<i64>();
+
+    //-- Execute Test (Act)
+    let response = client
+        .get(format!(
+            "{}/things?limit={random_limit}&offset={random_offset}",
+            &app.address
+        ))
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    // Check http status is ok (200)
+    assert_eq!(200, response.status().as_u16());
+    // Check the page respects the limit and offset
+    let page: Paginated<Thing> = serde_json::from_str(&response.text().await?)?;
+    let expected_records = random_limit.min(random_count - random_offset);
+    assert_eq!(page.data.len() as i64, expected_records);
+    assert_eq!(page.total, random_count);
+    assert_eq!(page.limit, random_limit);
+    assert_eq!(page.offset, random_offset);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn get_things_index_in_a_client_time_zone(database_pool: Pool<Postgres>) -> Result<()> {
+    use chrono::TimeZone;
+
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool.clone()).await?;
+    let client = reqwest::Client::new();
+    let created_at = chrono::Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let test_thing = ThingBuilder::new(ThingName::parse(Word().fake::<String>())?)
+        .created_at(created_at)
+        .updated_at(created_at)
+        .build()?;
+    insert(&test_thing, &database_pool).await?;
+
+    //-- Execute Test (Act)
+    let response = client
+        .get(format!("{}/things", &app.address))
+        .header("Time-Zone", "Australia/Brisbane")
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    assert_eq!(200, response.status().as_u16());
+    let body: serde_json::Value = serde_json::from_str(&response.text().await?)?;
+    assert_eq!(body["data"][0]["created_at"], "2024-01-01T10:00:00.000+10:00");
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn get_things_count(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+    let random_count: i64 = (2..10).fake::<i64>();
+    let mut thing_ids = Vec::new();
+    for count in 0..random_count {
+        // Thing names are unique
+        let name: String = format!("{} {count}", Word().fake::<String>());
+        let description: String = Sentence(3..7).fake();
+        let body = client
+            .post(format!("{}/things", &app.address))
+            .form(&[("name", name), ("description", description)])
+            .send()
+            .await?
+            .text()
+            .await?;
+        let thing: Thing = serde_json::from_str(&body)?;
+        thing_ids.push(thing.id);
+    }
+    // Soft deleted Things are not counted
+    soft_delete_by_id(&thing_ids[0], &app.database_pool).await?;
+
+    //-- Execute Test (Act)
+    let response = client
+        .get(format!("{}/things/count", &app.address))
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    // Check http status is ok (200)
+    assert_eq!(200, response.status().as_u16());
+    // Check the count excludes the soft deleted Thing
+    let body: serde_json::Value = serde_json::from_str(&response.text().await?)?;
+    assert_eq!(body, serde_json::json!({ "count": random_count - 1 }));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn get_things_index_with_negative_offset_returns_400(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+
+    //-- Execute Test (Act)
+    let response = client
+        .get(format!("{}/things?offset=-1", &app.address))
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    // Check http status is bad request (400)
+    assert_eq!(400, response.status().as_u16());
+    let body: serde_json::Value = serde_json::from_str(&response.text().await?)?;
+    assert_eq!(body["code"], "ParameterNegative");
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn get_things_index_clamps_an_over_max_limit(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+    let max_limit = Configuration::parse()?.application.pagination.max_limit;
+
+    //-- Execute Test (Act)
+    let response = client
+        .get(format!("{}/things?limit={}", &app.address, max_limit * 1000))
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    // Check http status is ok (200)
+    assert_eq!(200, response.status().as_u16());
+    // Check the page reports the effective, clamped, limit
+    let page: Paginated<Thing> = serde_json::from_str(&response.text().await?)?;
+    assert_eq!(page.limit, max_limit);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn get_thing_by_path_id(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+    let name: String = Word().fake();
+    let description: String = Sentence(3..7).fake();
+    let body = client
+        .post(format!("{}/things", &app.address))
+        .form(&[("name", name), ("description", description)])
+        .send()
+        .await?
+        .text()
+        .await?;
+    let thing: Thing = serde_json::from_str(&body)?;
+
+    //-- Execute Test (Act)
+    let response = client
+        .get(format!("{}/things/{}", &app.address, thing.id))
+        .send()
+        .await?;
+    let unknown_response = client
+        .get(format!("{}/things/{}", &app.address, uuid::Uuid::now_v7()))
+        .send()
+        .await?;
+    let malformed_response = client
+        .get(format!("{}/things/not-a-uuid", &app.address))
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    // Check http status is ok (200) and returns the Thing
+    assert_eq!(200, response.status().as_u16());
+    let response_thing: Thing = serde_json::from_str(&response.text().await?)?;
+    assert_eq!(response_thing.id, thing.id);
+    // Check an id without a Thing is not found (404)
+    assert_eq!(404, unknown_response.status().as_u16());
+    // Check a malformed id is a json bad request (400)
+    assert_eq!(400, malformed_response.status().as_u16());
+    let body: serde_json::Value = serde_json::from_str(&malformed_response.text().await?)?;
+    assert_eq!(body["code"], "PathParameterInvalid");
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn put_thing_with_stale_version_returns_409(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+    let name: String = Word().fake();
+    let description: String = Sentence(3..7).fake();
+    let body = client
+        .post(format!("{}/things", &app.address))
+        .form(&[("name", name.clone()), ("description", description)])
+        .send()
+        .await?
+        .text()
+        .await?;
+    let thing: Thing = serde_json::from_str(&body)?;
+    let version = thing.version.to_string();
+
+    //-- Execute Test (Act)
+    // Both updates are based on the version read above
+    let first_response = client
+        .put(format!("{}/things/{}", &app.address, thing.id))
+        .form(&[("name", format!("{name} first")), ("version", version.clone())])
+        .send()
+        .await?;
+    let second_response = client
+        .put(format!("{}/things/{}", &app.address, thing.id))
+        .form(&[("name", format!("{name} second")), ("version", version)])
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    // Check the first update is ok (200) and increments the version
+    assert_eq!(200, first_response.status().as_u16());
+    let updated_thing: Thing = serde_json::from_str(&first_response.text().await?)?;
+    assert_eq!(updated_thing.version, thing.version + 1);
+    // Check the second, stale, update is a conflict (409)
+    assert_eq!(409, second_response.status().as_u16());
+    let body: serde_json::Value = serde_json::from_str(&second_response.text().await?)?;
+    assert_eq!(body["code"], "StaleUpdate");
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn get_things_index_uses_the_configured_default_limit(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let default_limit: i64 = 3;
+    let app = spawn_app_with(database_pool, |configuration| {
+        configuration.application.pagination.default_limit = default_limit;
+    })
+    .await?;
+    let client = reqwest::Client::new();
+    for count in 0..default_limit + 2 {
+        // Thing names are unique
+        let name: String = format!("{} {count}", Word().fake::<String>());
+        client
+            .post(format!("{}/things", &app.address))
+            .form(&[("name", name)])
+            .send()
+            .await?;
+    }
+
+    //-- Execute Test (Act)
+    let response = client
+        .get(format!("{}/things", &app.address))
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    // Check http status is ok (200)
+    assert_eq!(200, response.status().as_u16());
+    // Check a request without a limit gets a page of the configured size
+    let page: Paginated<Thing> = serde_json::from_str(&response.text().await?)?;
+    assert_eq!(page.limit, default_limit);
+    assert_eq!(page.data.len() as i64, default_limit);
+    assert_eq!(page.total, default_limit + 2);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn get_things_index_with_a_full_text_query(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+    let things = [
+        ("Shed", "Garden tools and a hose"),
+        ("Pantry", "Flour, sugar and rice"),
+    ];
+    for (name, description) in things {
+        client
+            .post(format!("{}/things", &app.address))
+            .form(&[("name", name), ("description", description)])
+            .send()
+            .await?;
+    }
+
+    //-- Execute Test (Act)
+    let response = client
+        .get(format!("{}/things?q=garden%20tool", &app.address))
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    // Check http status is ok (200)
+    assert_eq!(200, response.status().as_u16());
+    // Check only the Thing with a matching description is indexed
+    let page: Paginated<Thing> = serde_json::from_str(&response.text().await?)?;
+    assert_eq!(page.total, 1);
+    assert_eq!(page.data[0].name.as_ref(), "Shed");
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn import_things(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+    let name: String = Word().fake();
+    let description: String = Sentence(3..7).fake();
+    let things = serde_json::json!([
+        { "name": "{invalid}" },
+        { "name": name, "description": description },
+        { "name": format!("{name} two") },
+        { "name": "" },
+    ]);
+
+    //-- Execute Test (Act)
+    let response = client
+        .post(format!("{}/things/import", &app.address))
+        .header("Content-Type", "application/json")
+        .body(things.to_string())
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    // Check http status is ok (200)
+    assert_eq!(200, response.status().as_u16());
+    // Check the summary counts the valid Things and indexes the invalid ones
+    let summary: serde_json::Value = serde_json::from_str(&response.text().await?)?;
+    assert_eq!(summary["inserted"], 2);
+    assert_eq!(summary["errors"][0]["index"], 0);
+    assert_eq!(summary["errors"][1]["index"], 3);
+    assert_eq!(summary["errors"].as_array().map(Vec::len), Some(2));
+    // Check the valid Things are in the index
+    let page: Paginated<Thing> = serde_json::from_str(
+        &client.get(format!("{}/things", &app.address)).send().await?.text().await?,
+    )?;
+    assert_eq!(page.total, 2);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn get_thing_with_etag_is_not_modified_until_updated(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+    let name: String = Word().fake();
+    let body = client
+        .post(format!("{}/things", &app.address))
+        .form(&[("name", name.clone())])
+        .send()
+        .await?
+        .text()
+        .await?;
+    let thing: Thing = serde_json::from_str(&body)?;
+    let thing_address = format!("{}/things/{}", &app.address, thing.id);
+
+    //-- Execute Test (Act)
+    let first_response = client.get(&thing_address).send().await?;
+    let etag = first_response.headers()["etag"].to_str()?.to_string();
+    let cached_response = client
+        .get(&thing_address)
+        .header("If-None-Match", &etag)
+        .send()
+        .await?;
+    client
+        .put(&thing_address)
+        .form(&[("name", format!("{name} updated"))])
+        .send()
+        .await?;
+    let updated_response = client
+        .get(&thing_address)
+        .header("If-None-Match", &etag)
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    // Check the first get is ok (200) with a weak etag
+    assert_eq!(200, first_response.status().as_u16());
+    assert!(etag.starts_with("W/\""));
+    // Check a get with the etag is not modified (304)
+    assert_eq!(304, cached_response.status().as_u16());
+    // Check a get after an update is ok (200) with a new etag
+    assert_eq!(200, updated_response.status().as_u16());
+    assert_ne!(updated_response.headers()["etag"].to_str()?, etag);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn get_thing_is_not_modified_since_until_updated(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+    let name: String = Word().fake();
+    let body = client
+        .post(format!("{}/things", &app.address))
+        .form(&[("name", name.clone())])
+        .send()
+        .await?
+        .text()
+        .await?;
+    let thing: Thing = serde_json::from_str(&body)?;
+    let thing_address = format!("{}/things/{}", &app.address, thing.id);
+
+    //-- Execute Test (Act)
+    let first_response = client.get(&thing_address).send().await?;
+    let last_modified = first_response.headers()["last-modified"].to_str()?.to_string();
+    let cached_response = client
+        .get(&thing_address)
+        .header("If-Modified-Since", &last_modified)
+        .send()
+        .await?;
+    // Wait for the next second, so the update is after `Last-Modified`
+    tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+    client
+        .put(&thing_address)
+        .form(&[("name", format!("{name} updated"))])
+        .send()
+        .await?;
+    let updated_response = client
+        .get(&thing_address)
+        .header("If-Modified-Since", &last_modified)
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    // Check the first get is ok (200) with a last modified date
+    assert_eq!(200, first_response.status().as_u16());
+    assert!(last_modified.ends_with(" GMT"));
+    // Check a get since the last modified date is not modified (304)
+    assert_eq!(304, cached_response.status().as_u16());
+    // Check a get after an update is ok (200) with a later last modified date
+    assert_eq!(200, updated_response.status().as_u16());
+    assert_ne!(updated_response.headers()["last-modified"].to_str()?, last_modified);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn get_things_export_streams_every_thing_as_ndjson(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+    let thing_count = 150;
+    for index in 0..thing_count {
+        let thing = ThingBuilder::new(ThingName::parse(format!("Export {index}"))?).build()?;
+        insert(&thing, &app.database_pool).await?;
+    }
+    // Soft deleted Things are not exported
+    let deleted = ThingBuilder::new(ThingName::parse("Export deleted")?).build()?;
+    insert(&deleted, &app.database_pool).await?;
+    soft_delete_by_id(&deleted.id, &app.database_pool).await?;
+
+    //-- Execute Test (Act)
+    let response = client
+        .get(format!("{}/things/export", &app.address))
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    assert_eq!(200, response.status().as_u16());
+    assert_eq!(response.headers().get("Content-Type").unwrap(), "application/x-ndjson");
+    let body = response.text().await?;
+    assert!(body.ends_with('\n'));
+    let things = body
+        .lines()
+        .map(serde_json::from_str::<Thing>)
+        .collect::<core::result::Result<Vec<Thing>, _>>()?;
+    assert_eq!(things.len(), thing_count);
+    assert!(things.iter().all(|thing| thing.id != deleted.id));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn get_things_events_streams_a_created_thing(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+    let mut response = client
+        .get(format!("{}/things/events", &app.address))
+        .send()
+        .await?;
+    assert_eq!(200, response.status().as_u16());
+    assert_eq!(response.headers().get("Content-Type").unwrap(), "text/event-stream");
+    assert_eq!(response.chunk().await?.unwrap(), ": connected\n\n");
+
+    //-- Execute Test (Act)
+    // Each chunk of the response body is an event
+    let events = futures::stream::unfold(response, |mut response| async move {
+        let chunk = response.chunk().await.ok()??;
+        Some((String::from_utf8_lossy(&chunk).into_owned(), response))
+    });
+    let (created_ids, event) = create_things_until_an_event(&app, Box::pin(events)).await?;
+
+    //-- Checks (Assertions)
+    let id = created_ids
+        .iter()
+        .find(|id| event.contains(&id.to_string()))
+        .expect("The event is not for a created Thing");
+    assert_eq!(
+        event,
+        format!("event: insert\ndata: {{\"action\":\"insert\",\"id\":\"{id}\"}}\n\n")
+    );
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn get_things_ws_sends_a_created_thing(database_pool: Pool<Postgres>) -> Result<()> {
+    use futures::{SinkExt, StreamExt};
+    use tokio_tungstenite::tungstenite::Message;
+
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let (mut socket, _) =
+        tokio_tungstenite::connect_async(format!("{}/things/ws", app.address.replacen("http", "ws", 1))).await?;
+    // Text frames are echoed, so the subscription is running once one returns
+    socket.send(Message::text("subscribed")).await?;
+    assert_eq!(socket.next().await.unwrap()?, Message::text("subscribed"));
+
+    //-- Execute Test (Act)
+    let frames = socket.by_ref().filter_map(|frame| async move {
+        match frame {
+            Ok(Message::Text(text)) => Some(text),
+            _ => None,
+        }
+    });
+    let (created_ids, frame) = create_things_until_an_event(&app, Box::pin(frames)).await?;
+
+    //-- Checks (Assertions)
+    let id = created_ids
+        .iter()
+        .find(|id| frame.contains(&id.to_string()))
+        .expect("The frame is not for a created Thing");
+    assert_eq!(frame, format!("{{\"action\":\"insert\",\"id\":\"{id}\"}}"));
+    socket.close(None).await?;
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn get_things_index_with_accept_encoding_gzip_is_compressed(database_pool: Pool<Postgres>) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let app = spawn_app(database_pool).await?;
+    let client = reqwest::Client::new();
+    for index in 0..20 {
+        let thing = ThingBuilder::new(ThingName::parse(format!("Compressed {index}"))?).build()?;
+        insert(&thing, &app.database_pool).await?;
+    }
+
+    //-- Execute Test (Act)
+    let response = client
+        .get(format!("{}/things", &app.address))
+        .header("Accept-Encoding", "gzip")
+        .send()
+        .await?;
+    let events = client
+        .get(format!("{}/things/events", &app.address))
+        .header("Accept-Encoding", "gzip")
+        .send()
+        .await?;
+
+    //-- Checks (Assertions)
+    assert_eq!(200, response.status().as_u16());
+    assert_eq!(response.headers().get("Content-Encoding").unwrap(), "gzip");
+    // Event streams are not compressed, so events are not held in the encoder
+    assert_eq!(events.headers().get("Content-Encoding").unwrap(), "identity");
+
+    Ok(())
+}